@@ -0,0 +1,396 @@
+use std::collections::HashMap;
+
+use crate::{Deinflector, Reasons, Rules, DEFAULT_DEINFLECTOR};
+
+/// Inflect a word using [`INFLECTION_RULES`](crate::INFLECTION_RULES), see
+/// [`Deinflector::inflect`].
+///
+/// ```rust
+/// use deinflect::{inflect, Reasons, Rules};
+///
+/// let inflections = inflect("聞く", Rules::V5, &[Reasons::PASSIVE, Reasons::POLITE_PAST]);
+/// assert_eq!(inflections, ["聞かれました"]);
+/// ```
+pub fn inflect(word: &str, rules: Rules, reasons: &[Reasons]) -> Vec<String> {
+    DEFAULT_DEINFLECTOR.inflect(word, rules, reasons)
+}
+
+/// Build the conjugation table of a word using
+/// [`INFLECTION_RULES`](crate::INFLECTION_RULES), see [`Deinflector::conjugate`].
+///
+/// ```rust
+/// use deinflect::{conjugate, Rules};
+///
+/// let table = conjugate("書く", Rules::V5);
+/// assert_eq!(table.negative, ["書かない"]);
+/// assert_eq!(table.polite_past, ["書きました"]);
+/// ```
+pub fn conjugate(word: &str, rules: Rules) -> ConjugationTable {
+    DEFAULT_DEINFLECTOR.conjugate(word, rules)
+}
+
+/// Find all chains of reasons up to some length using
+/// [`INFLECTION_RULES`](crate::INFLECTION_RULES), see
+/// [`Deinflector::inflection_chains`].
+///
+/// ```rust
+/// use deinflect::{inflection_chains, Reasons, Rules};
+///
+/// let chains = inflection_chains("書く", Rules::V5, Reasons::NEGATIVE | Reasons::PAST, 2);
+/// assert_eq!(
+///     chains.get(&[Reasons::NEGATIVE, Reasons::PAST]),
+///     Some(&["書かなかった".to_string()][..])
+/// );
+/// ```
+pub fn inflection_chains(
+    word: &str,
+    rules: Rules,
+    reasons: Reasons,
+    depth: usize,
+) -> InflectionChains {
+    DEFAULT_DEINFLECTOR.inflection_chains(word, rules, reasons, depth)
+}
+
+/// The common forms of a word, see [`Deinflector::conjugate`]. Forms the
+/// rules don't have for the class of the word are empty, e.g. the potential
+/// of an adjective.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ConjugationTable {
+    pub negative: Vec<String>,
+    pub past: Vec<String>,
+    pub te: Vec<String>,
+    /// Includes the forms that are both potential and passive, like 食べられる.
+    pub potential: Vec<String>,
+    /// Includes the forms that are both potential and passive, like 食べられる.
+    pub passive: Vec<String>,
+    pub causative: Vec<String>,
+    pub volitional: Vec<String>,
+    pub imperative: Vec<String>,
+    pub polite: Vec<String>,
+    pub polite_negative: Vec<String>,
+    pub polite_past: Vec<String>,
+    pub polite_past_negative: Vec<String>,
+    pub polite_volitional: Vec<String>,
+}
+
+/// All inflections of a word up to some number of reasons, see
+/// [`Deinflector::inflection_chains`].
+#[derive(Debug, Clone)]
+pub struct InflectionChains {
+    chains: Vec<InflectionChain>,
+    index: HashMap<Vec<Reasons>, usize>,
+}
+
+/// The forms of a word after applying a chain of reasons.
+#[derive(Debug, Clone)]
+pub struct InflectionChain {
+    /// The reasons in the order they were applied to the word, see
+    /// [`Deinflector::inflect`].
+    pub reasons: Vec<Reasons>,
+    pub forms: Vec<String>,
+}
+
+impl InflectionChains {
+    /// Get the forms for the given chain of reasons.
+    pub fn get(&self, reasons: &[Reasons]) -> Option<&[String]> {
+        self.index
+            .get(reasons)
+            .map(|&i| self.chains[i].forms.as_slice())
+    }
+
+    /// Iterate over the chains, ordered by the number of reasons and then
+    /// by the order of the rules in the deinflector. The first chain is the
+    /// word itself.
+    pub fn iter(&self) -> impl Iterator<Item = &InflectionChain> + '_ {
+        self.chains.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.chains.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.chains.is_empty()
+    }
+}
+
+impl Deinflector {
+    /// Inflect a word of the given class by applying the rules of the
+    /// deinflector in reverse.
+    ///
+    /// `reasons` are applied in order starting with the one closest to the
+    /// word, which is the same order in which [`Deinflections::steps`]
+    /// yields them, so the reasons of each step of a deinflection lead back
+    /// to the inflected word. Each element should be a single reason.
+    ///
+    /// All surface forms the rules allow are returned, for words the rules
+    /// don't apply to the result is empty.
+    ///
+    /// [`Deinflections::steps`]: crate::Deinflections::steps
+    pub fn inflect(&self, word: &str, rules: Rules, reasons: &[Reasons]) -> Vec<String> {
+        let mut forms = vec![(word.to_string(), rules)];
+        for &reason in reasons {
+            forms = self.inflect_once(&forms, reason);
+        }
+        forms.into_iter().map(|(form, _)| form).collect()
+    }
+
+    /// Build the table of the common forms of a word of the given class,
+    /// each is the result of [`Deinflector::inflect`] with a fixed chain of
+    /// reasons.
+    pub fn conjugate(&self, word: &str, rules: Rules) -> ConjugationTable {
+        let forms = |chains: &[Reasons]| {
+            let mut forms: Vec<String> = Vec::new();
+            for &reason in chains {
+                for form in self.inflect(word, rules, &[reason]) {
+                    if !forms.contains(&form) {
+                        forms.push(form);
+                    }
+                }
+            }
+            forms
+        };
+
+        ConjugationTable {
+            negative: forms(&[Reasons::NEGATIVE]),
+            past: forms(&[Reasons::PAST]),
+            te: forms(&[Reasons::TE]),
+            potential: forms(&[Reasons::POTENTIAL, Reasons::POTENTIAL_OR_PASSIVE]),
+            passive: forms(&[Reasons::PASSIVE, Reasons::POTENTIAL_OR_PASSIVE]),
+            causative: forms(&[Reasons::CAUSATIVE]),
+            volitional: forms(&[Reasons::VOLITIONAL]),
+            imperative: forms(&[Reasons::IMPERATIVE]),
+            polite: forms(&[Reasons::POLITE]),
+            polite_negative: forms(&[Reasons::POLITE_NEGATIVE]),
+            polite_past: forms(&[Reasons::POLITE_PAST]),
+            polite_past_negative: forms(&[Reasons::POLITE_PAST_NEGATIVE]),
+            polite_volitional: forms(&[Reasons::POLITE_VOLITIONAL]),
+        }
+    }
+
+    /// Find all inflections of a word of the given class that need at most
+    /// `depth` rules, using only the given `reasons`.
+    ///
+    /// For example a depth of 2 with all reasons includes the negative,
+    /// the past, the negative past, the polite past and so on.
+    ///
+    /// The number of chains grows exponentially with `depth`, with all
+    /// reasons a depth of 3 already gives over ten thousand chains. Keep the
+    /// depth at 2 or below, or restrict `reasons` for deeper chains. Use
+    /// [`Deinflector::conjugate`] for the common forms.
+    pub fn inflection_chains(
+        &self,
+        word: &str,
+        rules: Rules,
+        reasons: Reasons,
+        depth: usize,
+    ) -> InflectionChains {
+        let mut candidates: Vec<Reasons> = Vec::new();
+        for inflection_rules in &self.rules {
+            if reasons.contains(inflection_rules.reason)
+                && !candidates.contains(&inflection_rules.reason)
+            {
+                candidates.push(inflection_rules.reason);
+            }
+        }
+
+        let mut chains = vec![InflectionChain {
+            reasons: Vec::new(),
+            forms: vec![word.to_string()],
+        }];
+        let mut layer = vec![(Vec::new(), vec![(word.to_string(), rules)])];
+
+        for _ in 0..depth {
+            let mut next_layer = Vec::new();
+            for (chain, forms) in &layer {
+                for &reason in &candidates {
+                    let forms = self.inflect_once(forms, reason);
+                    if forms.is_empty() {
+                        continue;
+                    }
+
+                    let mut chain = chain.clone();
+                    chain.push(reason);
+                    chains.push(InflectionChain {
+                        reasons: chain.clone(),
+                        forms: forms.iter().map(|(form, _)| form.clone()).collect(),
+                    });
+                    next_layer.push((chain, forms));
+                }
+            }
+            layer = next_layer;
+        }
+
+        let index = chains
+            .iter()
+            .enumerate()
+            .map(|(i, chain)| (chain.reasons.clone(), i))
+            .collect();
+        InflectionChains { chains, index }
+    }
+
+    /// Apply all rules of the reason to the forms, the rules of each form
+    /// are the classes of the word it is a valid inflection of.
+    fn inflect_once(&self, forms: &[(String, Rules)], reason: Reasons) -> Vec<(String, Rules)> {
+        let mut next: Vec<(String, Rules)> = Vec::new();
+
+        for (form, form_rules) in forms {
+            let rules = || {
+                self.rules
+                    .iter()
+                    .filter(|r| r.reason == reason)
+                    .flat_map(|r| r.rules.iter())
+            };
+            // rules that replace part of the word are exceptions for that
+            // word, like ご覧になる for 見る instead of お見になる
+            let exception = rules().any(|rule| {
+                !rule.prefix_out.is_empty()
+                    && rule.rules_out.intersects(*form_rules)
+                    && form.starts_with(&*rule.prefix_out)
+                    && form.ends_with(&*rule.kana_out)
+            });
+
+            for rule in rules() {
+                if exception && rule.prefix_out.is_empty() {
+                    continue;
+                }
+                // mirrors the check in `Deinflector::deinflect`, where the
+                // inflected form is the source of the deinflection
+                let applies = rule.rules_out.is_empty() || rule.rules_out.intersects(*form_rules);
+                if !applies {
+                    continue;
+                }
+                let Some(stem) = form
+                    .strip_prefix(&*rule.prefix_out)
+                    .and_then(|f| f.strip_suffix(&*rule.kana_out))
+                else {
+                    continue;
+                };
+                // a rule that only adds a prefix needs a stem, like the
+                // check in `Deinflector::deinflect`
+                if !rule.prefix_in.is_empty()
+                    && rule.prefix_out.is_empty()
+                    && stem.is_empty()
+                    && !rule.kana_in.is_empty()
+                {
+                    continue;
+                }
+                let inflected = format!("{}{stem}{}", rule.prefix_in, rule.kana_in);
+                match next.iter_mut().find(|(f, _)| *f == inflected) {
+                    Some((_, r)) => *r |= rule.rules_in,
+                    None => next.push((inflected, rule.rules_in)),
+                }
+            }
+        }
+
+        next
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inflect_chain() {
+        assert_eq!(
+            inflect("聞く", Rules::V5, &[Reasons::PASSIVE, Reasons::POLITE_PAST]),
+            ["聞かれました"]
+        );
+        assert_eq!(
+            inflect("食べる", Rules::V1, &[Reasons::NEGATIVE]),
+            ["食べない"]
+        );
+        assert_eq!(
+            inflect("食べる", Rules::V1, &[Reasons::NEGATIVE, Reasons::PAST]),
+            ["食べなかった"]
+        );
+        assert!(
+            inflect("する", Rules::VS, &[Reasons::CAUSATIVE, Reasons::TE])
+                .contains(&"させて".to_string())
+        );
+        assert_eq!(
+            inflect(
+                "書く",
+                Rules::V5,
+                &[Reasons::TE, Reasons::PROGRESSIVE_OR_PERFECT]
+            )
+            .iter()
+            .filter(|f| *f == "書いている")
+            .count(),
+            1
+        );
+        assert_eq!(inflect("聞く", Rules::V5, &[]), ["聞く"]);
+    }
+
+    #[test]
+    fn inflect_respects_rules() {
+        // past of 聞く is a terminal form that can't be made polite
+        assert!(inflect("聞く", Rules::V5, &[Reasons::PAST, Reasons::POLITE]).is_empty());
+        // ichidan endings don't apply to godan verbs
+        assert!(!inflect("切る", Rules::V5, &[Reasons::NEGATIVE]).contains(&"切ない".to_string()));
+        assert!(inflect("切る", Rules::V5, &[Reasons::NEGATIVE]).contains(&"切らない".to_string()));
+        // rules with a prefix only apply to words starting with it
+        assert_eq!(
+            inflect("見る", Rules::V1, &[Reasons::HONORIFIC]),
+            ["ご覧になる", "ご覧くださる"]
+        );
+        assert!(inflect("夢見る", Rules::V1, &[Reasons::HONORIFIC])
+            .iter()
+            .all(|f| !f.contains('覧')));
+    }
+
+    #[test]
+    fn conjugation_table() {
+        let table = conjugate("食べる", Rules::V1);
+        assert_eq!(table.negative, ["食べない"]);
+        assert_eq!(table.past, ["食べた"]);
+        assert_eq!(table.te, ["食べて"]);
+        assert!(table.potential.contains(&"食べられる".to_string()));
+        assert_eq!(table.passive, ["食べられる"]);
+        assert_eq!(table.causative, ["食べさせる"]);
+        assert_eq!(table.volitional, ["食べよう"]);
+        assert_eq!(table.polite, ["食べます"]);
+        assert_eq!(table.polite_negative, ["食べません"]);
+        assert_eq!(table.polite_past, ["食べました"]);
+        assert_eq!(table.polite_past_negative, ["食べませんでした"]);
+        assert_eq!(table.polite_volitional, ["食べましょう"]);
+
+        let table = conjugate("書く", Rules::V5);
+        assert_eq!(table.potential, ["書ける"]);
+        assert_eq!(table.passive, ["書かれる"]);
+
+        // adjectives have no potential
+        assert!(conjugate("高い", Rules::ADJ_I).potential.is_empty());
+    }
+
+    #[test]
+    fn inflection_chains() {
+        let chains = super::inflection_chains("食べる", Rules::V1, Reasons::all(), 2);
+
+        assert_eq!(chains.get(&[]), Some(&["食べる".to_string()][..]));
+        for (reasons, form) in [
+            (&[Reasons::NEGATIVE][..], "食べない"),
+            (&[Reasons::POTENTIAL_OR_PASSIVE], "食べられる"),
+            (&[Reasons::NEGATIVE, Reasons::PAST], "食べなかった"),
+            (&[Reasons::CAUSATIVE, Reasons::POLITE], "食べさせます"),
+        ] {
+            let forms = chains.get(reasons).unwrap_or_default();
+            assert!(forms.iter().any(|f| f == form), "{reasons:?}: {forms:?}");
+        }
+        // past forms can't be inflected further
+        assert!(chains.get(&[Reasons::PAST, Reasons::NEGATIVE]).is_none());
+        assert!(chains.iter().all(|c| c.reasons.len() <= 2));
+
+        let chains =
+            super::inflection_chains("食べる", Rules::V1, Reasons::NEGATIVE | Reasons::PAST, 3);
+        assert!(chains
+            .iter()
+            .flat_map(|c| &c.reasons)
+            .all(|r| *r == Reasons::NEGATIVE || *r == Reasons::PAST));
+        assert_eq!(
+            chains.get(&[Reasons::NEGATIVE, Reasons::NEGATIVE, Reasons::PAST]),
+            Some(&["食べなくなかった".to_string()][..])
+        );
+    }
+}
@@ -0,0 +1,600 @@
+//! Loading inflection rules from json at runtime.
+//!
+//! ```rust
+//! use deinflect::{json, Deinflector, INFLECTION_RULES};
+//!
+//! let rules = json::from_deinflect_json(
+//!     r#"{
+//!         "negative": [
+//!             { "kanaIn": "らん", "kanaOut": "る", "rulesIn": [], "rulesOut": ["v5"] }
+//!         ]
+//!     }"#,
+//! )
+//! .unwrap();
+//!
+//! let mut deinflector = Deinflector::new(INFLECTION_RULES.iter().cloned());
+//! deinflector.add_rules(rules);
+//!
+//! let deinflections = deinflector.deinflect("知らん");
+//! assert!(deinflections
+//!     .iter()
+//!     .any(|d| deinflections.to_string(d) == "知る"));
+//! ```
+use crate::{InflectionRules, Reasons, RuleInfo, Rules};
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::marker::PhantomData;
+
+/// Parse rules in the format of yomichan's `deinflect.json`, that is
+/// `{reason: [{kanaIn, kanaOut, rulesIn, rulesOut}]}`, rules can also have a
+/// `prefixIn` and `prefixOut`, see [`RuleInfo::prefix_in`].
+///
+/// Reason names are matched against [`Reasons`] and rule names against
+/// [`Rules`] after dropping leading dashes, replacing spaces and dashes
+/// with underscores and uppercasing, so `"polite past"` is
+/// [`Reasons::POLITE_PAST`] and `"adj-i"` is [`Rules::ADJ_I`].
+pub fn from_deinflect_json(json: &str) -> Result<Vec<InflectionRules>, Error> {
+    let OrderedMap::<Vec<RuleJson>>(entries) = serde_json::from_str(json).map_err(Error::Json)?;
+
+    entries
+        .into_iter()
+        .map(|(reason, rules)| {
+            Ok(InflectionRules {
+                reason: reason_from_name(&reason)?,
+                rules: rules
+                    .into_iter()
+                    .map(|rule| {
+                        Ok(RuleInfo::new(
+                            rule.kana_in,
+                            rule.kana_out,
+                            rules_from_names(&rule.rules_in)?,
+                            rules_from_names(&rule.rules_out)?,
+                        )
+                        .with_prefix(rule.prefix_in, rule.prefix_out))
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?
+                    .into(),
+            })
+        })
+        .collect()
+}
+
+/// Parse rules from a yomitan language transform descriptor, see
+/// [`LanguageTransformDescriptor`].
+pub fn from_transforms_json(json: &str) -> Result<Vec<InflectionRules>, Error> {
+    LanguageTransformDescriptor::from_json(json)?.to_inflection_rules()
+}
+
+/// The json form of yomitan's language transform descriptors, which replaced
+/// `deinflect.json`:
+///
+/// ```json
+/// {
+///     "language": "ja",
+///     "conditions": {
+///         "v": { "name": "Verb", "isDictionaryForm": false, "subConditions": ["v1", "v5"] },
+///         "v1": { "name": "Ichidan verb", "isDictionaryForm": true, "subConditions": ["v1d"] },
+///         "v1d": { "name": "Ichidan verb, dictionary form", "isDictionaryForm": false },
+///         "v5": { "name": "Godan verb", "isDictionaryForm": true },
+///         "-te": { "name": "Intermediate -te round", "isDictionaryForm": false }
+///     },
+///     "transforms": {
+///         "-te": {
+///             "name": "-te",
+///             "description": "te-form.",
+///             "rules": [
+///                 {
+///                     "type": "suffix",
+///                     "inflected": "て",
+///                     "deinflected": "る",
+///                     "conditionsIn": ["-te"],
+///                     "conditionsOut": ["v1"]
+///                 }
+///             ]
+///         }
+///     }
+/// }
+/// ```
+///
+/// `inflected` and `deinflected` are the affixes passed to yomitan's
+/// `suffixInflection` or `prefixInflection`, other fields such as `i18n` are
+/// ignored.
+#[derive(Debug, Clone, Deserialize)]
+pub struct LanguageTransformDescriptor {
+    pub language: String,
+    /// The conditions by name, sorted so that conversions don't depend on
+    /// the order of a hash map.
+    pub conditions: BTreeMap<String, Condition>,
+    /// The transforms in the order they appear in the descriptor.
+    #[serde(deserialize_with = "deserialize_ordered_map")]
+    pub transforms: Vec<(String, Transform)>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Condition {
+    pub name: String,
+    #[serde(default)]
+    pub is_dictionary_form: bool,
+    #[serde(default)]
+    pub sub_conditions: Vec<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+pub struct Transform {
+    pub name: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    pub rules: Vec<TransformRule>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TransformRule {
+    /// Only `"suffix"` and `"prefix"` rules are supported.
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub inflected: String,
+    pub deinflected: String,
+    pub conditions_in: Vec<String>,
+    pub conditions_out: Vec<String>,
+}
+
+impl LanguageTransformDescriptor {
+    pub fn from_json(json: &str) -> Result<Self, Error> {
+        serde_json::from_str(json).map_err(Error::Json)
+    }
+
+    /// Convert the transforms to [`InflectionRules`].
+    ///
+    /// Transform names are matched against [`Reasons`] like the reasons of
+    /// [`from_deinflect_json`]. Conditions are mapped to [`Rules`] as follows:
+    /// - conditions named like one of the [`Rules`] map to that rule,
+    /// - conditions with sub-conditions map to the union of their
+    ///   sub-conditions, e.g. `v` to `V1 | V5 | ...`,
+    /// - sub-conditions such as `v1d` map to the rule of their parent,
+    /// - remaining conditions that aren't dictionary forms, e.g. `-te` or
+    ///   `masu`, map to [`Rules::empty()`], which is how `deinflect.json`
+    ///   describes these intermediate forms.
+    ///
+    /// Transforms that don't have a corresponding reason can be removed from
+    /// [`LanguageTransformDescriptor::transforms`] before converting.
+    pub fn to_inflection_rules(&self) -> Result<Vec<InflectionRules>, Error> {
+        let mut cache = HashMap::new();
+        let mut conditions = |names: &[String]| {
+            names.iter().try_fold(Rules::empty(), |acc, name| {
+                Ok::<_, Error>(acc | self.condition_rules(name, &mut cache, 0)?)
+            })
+        };
+
+        self.transforms
+            .iter()
+            .map(|(name, transform)| {
+                let reason = reason_from_name(name)?;
+                let rules = transform
+                    .rules
+                    .iter()
+                    .map(|rule| {
+                        let rules_in = conditions(&rule.conditions_in)?;
+                        let rules_out = conditions(&rule.conditions_out)?;
+                        match rule.kind.as_str() {
+                            "suffix" => Ok(RuleInfo::new(
+                                rule.inflected.clone(),
+                                rule.deinflected.clone(),
+                                rules_in,
+                                rules_out,
+                            )),
+                            "prefix" => Ok(RuleInfo::new("", "", rules_in, rules_out)
+                                .with_prefix(rule.inflected.clone(), rule.deinflected.clone())),
+                            kind => Err(Error::UnsupportedRuleType(kind.to_string())),
+                        }
+                    })
+                    .collect::<Result<Vec<_>, Error>>()?;
+                Ok(InflectionRules {
+                    reason,
+                    rules: rules.into(),
+                })
+            })
+            .collect()
+    }
+
+    fn condition_rules(
+        &self,
+        name: &str,
+        cache: &mut HashMap<String, Rules>,
+        depth: usize,
+    ) -> Result<Rules, Error> {
+        if let Some(rules) = cache.get(name) {
+            return Ok(*rules);
+        }
+        // conditions are nested only a few levels deep, anything deeper is a cycle
+        if depth > self.conditions.len() {
+            return Err(Error::UnknownCondition(name.to_string()));
+        }
+
+        let rules = if let Some(rules) = Rules::from_name(&flag_name(name)) {
+            rules
+        } else {
+            let condition = self
+                .conditions
+                .get(name)
+                .ok_or_else(|| Error::UnknownCondition(name.to_string()))?;
+
+            if !condition.sub_conditions.is_empty() {
+                condition
+                    .sub_conditions
+                    .iter()
+                    .try_fold(Rules::empty(), |acc, sub| {
+                        Ok::<_, Error>(acc | self.condition_rules(sub, cache, depth + 1)?)
+                    })?
+            } else if let Some(rules) = self.parent_rules(name, depth) {
+                rules
+            } else if !condition.is_dictionary_form {
+                Rules::empty()
+            } else {
+                return Err(Error::UnknownCondition(name.to_string()));
+            }
+        };
+
+        cache.insert(name.to_string(), rules);
+        Ok(rules)
+    }
+
+    /// The rule of the closest ancestor of the condition that corresponds to
+    /// one of the [`Rules`], for conditions with several parents the first
+    /// parent by name that has one.
+    fn parent_rules(&self, name: &str, depth: usize) -> Option<Rules> {
+        if depth > self.conditions.len() {
+            return None;
+        }
+
+        self.conditions
+            .iter()
+            .filter(|(_, condition)| condition.sub_conditions.iter().any(|sub| sub == name))
+            .find_map(|(parent, _)| {
+                Rules::from_name(&flag_name(parent))
+                    .or_else(|| self.parent_rules(parent, depth + 1))
+            })
+    }
+}
+
+#[derive(Debug)]
+pub enum Error {
+    Json(serde_json::Error),
+    /// The name does not correspond to any of the [`Reasons`].
+    UnknownReason(String),
+    /// The name does not correspond to any of the [`Rules`].
+    UnknownRule(String),
+    /// The condition of a [`LanguageTransformDescriptor`] can't be mapped
+    /// to [`Rules`].
+    UnknownCondition(String),
+    /// The type of a [`TransformRule`] isn't supported.
+    UnsupportedRuleType(String),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Json(e) => write!(f, "invalid json: {e}"),
+            Error::UnknownReason(name) => write!(f, "unknown reason `{name}`"),
+            Error::UnknownRule(name) => write!(f, "unknown rule `{name}`"),
+            Error::UnknownCondition(name) => write!(f, "unknown condition `{name}`"),
+            Error::UnsupportedRuleType(kind) => write!(f, "unsupported rule type `{kind}`"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Json(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+fn flag_name(name: &str) -> String {
+    name.trim_start_matches('-')
+        .replace([' ', '-'], "_")
+        .to_uppercase()
+}
+
+pub(crate) fn reason_from_name(name: &str) -> Result<Reasons, Error> {
+    Reasons::from_name(&flag_name(name)).ok_or_else(|| Error::UnknownReason(name.to_string()))
+}
+
+pub(crate) fn rules_from_names(names: &[String]) -> Result<Rules, Error> {
+    names.iter().try_fold(Rules::empty(), |acc, name| {
+        Rules::from_name(&flag_name(name))
+            .map(|rule| acc | rule)
+            .ok_or_else(|| Error::UnknownRule(name.to_string()))
+    })
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleJson {
+    #[serde(default)]
+    prefix_in: String,
+    #[serde(default)]
+    prefix_out: String,
+    kana_in: String,
+    kana_out: String,
+    rules_in: Vec<String>,
+    rules_out: Vec<String>,
+}
+
+/// A json object as a list of entries in the order they appear in the file,
+/// so that the resulting [`RuleId`](crate::RuleId)s don't depend on hashing.
+struct OrderedMap<T>(Vec<(String, T)>);
+
+impl<'de, T: Deserialize<'de>> Deserialize<'de> for OrderedMap<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct OrderedMapVisitor<T>(PhantomData<T>);
+
+        impl<'de, T: Deserialize<'de>> Visitor<'de> for OrderedMapVisitor<T> {
+            type Value = OrderedMap<T>;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(OrderedMap(entries))
+            }
+        }
+
+        deserializer.deserialize_map(OrderedMapVisitor(PhantomData))
+    }
+}
+
+fn deserialize_ordered_map<'de, D: Deserializer<'de>, T: Deserialize<'de>>(
+    deserializer: D,
+) -> Result<Vec<(String, T)>, D::Error> {
+    OrderedMap::deserialize(deserializer).map(|OrderedMap(entries)| entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Deinflector;
+
+    #[test]
+    fn deinflect_json() {
+        let rules = from_deinflect_json(
+            r#"{
+                "polite past": [
+                    { "kanaIn": "ました", "kanaOut": "る", "rulesIn": [], "rulesOut": ["v1"] },
+                    { "kanaIn": "きました", "kanaOut": "く", "rulesIn": [], "rulesOut": ["v5"] }
+                ],
+                "-te": [
+                    { "kanaIn": "くて", "kanaOut": "い", "rulesIn": ["iru"], "rulesOut": ["adj-i"] }
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0].reason, Reasons::POLITE_PAST);
+        assert_eq!(rules[0].rules.len(), 2);
+        assert_eq!(rules[1].reason, Reasons::TE);
+        assert_eq!(rules[1].rules[0].kana_in, "くて");
+        assert_eq!(rules[1].rules[0].rules_in, Rules::IRU);
+        assert_eq!(rules[1].rules[0].rules_out, Rules::ADJ_I);
+
+        let deinflector = Deinflector::new(rules);
+        let d = deinflector.deinflect("書きました");
+        assert!(d.iter().any(|f| d.to_string(f) == "書く"));
+    }
+
+    #[test]
+    fn deinflect_json_matches_inflection_rules() {
+        for (json, expected) in [
+            (
+                include_str!("../data/deinflect.json"),
+                crate::INFLECTION_RULES,
+            ),
+            (
+                include_str!("../data/colloquial.json"),
+                crate::COLLOQUIAL_RULES,
+            ),
+            (
+                include_str!("../data/classical.json"),
+                crate::CLASSICAL_RULES,
+            ),
+            (include_str!("../data/kansai.json"), crate::KANSAI_RULES),
+        ] {
+            let rules = from_deinflect_json(json).unwrap();
+
+            assert_eq!(rules.len(), expected.len());
+            for (a, b) in rules.iter().zip(expected) {
+                assert_eq!(a.reason, b.reason);
+                assert_eq!(a.rules.len(), b.rules.len());
+                for (a, b) in a.rules.iter().zip(b.rules.iter()) {
+                    assert_eq!(
+                        (
+                            &a.prefix_in,
+                            &a.prefix_out,
+                            &a.kana_in,
+                            &a.kana_out,
+                            a.rules_in,
+                            a.rules_out
+                        ),
+                        (
+                            &b.prefix_in,
+                            &b.prefix_out,
+                            &b.kana_in,
+                            &b.kana_out,
+                            b.rules_in,
+                            b.rules_out
+                        )
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn deinflect_json_errors() {
+        let unknown_reason = from_deinflect_json(
+            r#"{ "-zutsu": [{ "kanaIn": "ずつ", "kanaOut": "", "rulesIn": [], "rulesOut": [] }] }"#,
+        );
+        assert!(matches!(unknown_reason, Err(Error::UnknownReason(name)) if name == "-zutsu"));
+
+        let unknown_rule = from_deinflect_json(
+            r#"{ "-te": [{ "kanaIn": "て", "kanaOut": "る", "rulesIn": ["v9"], "rulesOut": [] }] }"#,
+        );
+        assert!(matches!(unknown_rule, Err(Error::UnknownRule(name)) if name == "v9"));
+
+        let invalid = from_deinflect_json(r#"{ "-te": [{ "kanaIn": "て" }] }"#);
+        assert!(matches!(invalid, Err(Error::Json(_))));
+    }
+
+    const TRANSFORMS: &str = r#"{
+        "language": "ja",
+        "conditions": {
+            "v": { "name": "Verb", "isDictionaryForm": false, "subConditions": ["v1", "v5", "vk", "vs", "vz"] },
+            "v1": { "name": "Ichidan verb", "isDictionaryForm": true, "subConditions": ["v1d", "v1p"] },
+            "v1d": { "name": "Ichidan verb, dictionary form", "isDictionaryForm": false },
+            "v1p": { "name": "Ichidan verb, progressive or perfect form", "isDictionaryForm": false },
+            "v5": { "name": "Godan verb", "isDictionaryForm": true, "subConditions": ["v5d", "v5s"] },
+            "v5d": { "name": "Godan verb, dictionary form", "isDictionaryForm": false },
+            "v5s": { "name": "Godan verb, short causative form", "isDictionaryForm": false, "subConditions": ["v5ss", "v5sp"] },
+            "v5ss": { "name": "Godan verb, short causative form having さす ending", "isDictionaryForm": false },
+            "v5sp": { "name": "Godan verb, short causative form not having さす ending", "isDictionaryForm": false },
+            "vk": { "name": "Kuru verb", "isDictionaryForm": true },
+            "vs": { "name": "Suru verb", "isDictionaryForm": true },
+            "vz": { "name": "Zuru verb", "isDictionaryForm": true },
+            "adj-i": { "name": "Adjective with i ending", "isDictionaryForm": true },
+            "-te": { "name": "Intermediate -te round", "isDictionaryForm": false },
+            "masu": { "name": "Intermediate -masu form", "isDictionaryForm": false }
+        },
+        "transforms": {
+            "-te": {
+                "name": "-te",
+                "description": "te-form.",
+                "i18n": [],
+                "rules": [
+                    { "type": "suffix", "inflected": "て", "deinflected": "る", "conditionsIn": ["-te"], "conditionsOut": ["v1"] },
+                    { "type": "suffix", "inflected": "いて", "deinflected": "く", "conditionsIn": ["-te"], "conditionsOut": ["v5"] }
+                ]
+            },
+            "progressive or perfect": {
+                "name": "progressive or perfect",
+                "rules": [
+                    { "type": "suffix", "inflected": "ている", "deinflected": "て", "conditionsIn": ["v1p"], "conditionsOut": ["-te"] }
+                ]
+            },
+            "causative": {
+                "name": "causative",
+                "rules": [
+                    { "type": "suffix", "inflected": "かす", "deinflected": "く", "conditionsIn": ["v5ss"], "conditionsOut": ["v"] }
+                ]
+            },
+            "honorific": {
+                "name": "honorific",
+                "rules": [
+                    { "type": "prefix", "inflected": "ご覧", "deinflected": "見", "conditionsIn": ["v1"], "conditionsOut": ["v1"] }
+                ]
+            }
+        }
+    }"#;
+
+    #[test]
+    fn transforms_json() {
+        let rules = from_transforms_json(TRANSFORMS).unwrap();
+
+        assert_eq!(rules.len(), 4);
+        assert_eq!(rules[0].reason, Reasons::TE);
+        assert_eq!(rules[0].rules[1].kana_in, "いて");
+        assert_eq!(rules[0].rules[1].rules_in, Rules::empty());
+        assert_eq!(rules[0].rules[1].rules_out, Rules::V5);
+        assert_eq!(rules[1].reason, Reasons::PROGRESSIVE_OR_PERFECT);
+        assert_eq!(rules[1].rules[0].rules_in, Rules::V1);
+        assert_eq!(rules[2].reason, Reasons::CAUSATIVE);
+        assert_eq!(rules[2].rules[0].rules_in, Rules::V5S);
+        assert_eq!(
+            rules[2].rules[0].rules_out,
+            Rules::V1 | Rules::V5 | Rules::VK | Rules::VS | Rules::VZ
+        );
+
+        assert_eq!(rules[3].rules[0].prefix_in, "ご覧");
+        assert_eq!(rules[3].rules[0].prefix_out, "見");
+        assert_eq!(rules[3].rules[0].kana_in, "");
+
+        let deinflector = Deinflector::new(rules);
+        let d = deinflector.deinflect("書いている");
+        assert!(d.iter().any(|f| d.to_string(f) == "書く"));
+        let d = deinflector.deinflect("ご覧ている");
+        assert!(d.iter().any(|f| d.to_string(f) == "見る"));
+    }
+
+    #[test]
+    fn transforms_json_several_parents() {
+        let mut descriptor = LanguageTransformDescriptor::from_json(TRANSFORMS).unwrap();
+        for parent in ["v5", "v1"] {
+            let condition = descriptor.conditions.get_mut(parent).unwrap();
+            condition.sub_conditions.push("v1x".to_string());
+        }
+        descriptor.conditions.insert(
+            "v1x".to_string(),
+            Condition {
+                name: "Verb with two parents".to_string(),
+                is_dictionary_form: false,
+                sub_conditions: Vec::new(),
+            },
+        );
+        descriptor.transforms[0].1.rules[0].conditions_out = vec!["v1x".to_string()];
+
+        let rules = descriptor.to_inflection_rules().unwrap();
+        assert_eq!(rules[0].rules[0].rules_out, Rules::V1);
+    }
+
+    #[test]
+    fn transforms_json_errors() {
+        let mut descriptor = LanguageTransformDescriptor::from_json(TRANSFORMS).unwrap();
+        descriptor.transforms[0].0 = "-kansai".to_string();
+        assert!(matches!(
+            descriptor.to_inflection_rules(),
+            Err(Error::UnknownReason(name)) if name == "-kansai"
+        ));
+
+        let mut descriptor = LanguageTransformDescriptor::from_json(TRANSFORMS).unwrap();
+        descriptor.transforms[0].1.rules[0].conditions_out = vec!["n".to_string()];
+        assert!(matches!(
+            descriptor.to_inflection_rules(),
+            Err(Error::UnknownCondition(name)) if name == "n"
+        ));
+
+        let mut descriptor = LanguageTransformDescriptor::from_json(TRANSFORMS).unwrap();
+        let cyclic = |name: &str, sub: &str| Condition {
+            name: name.to_string(),
+            is_dictionary_form: false,
+            sub_conditions: vec![sub.to_string()],
+        };
+        descriptor
+            .conditions
+            .insert("a".to_string(), cyclic("a", "b"));
+        descriptor
+            .conditions
+            .insert("b".to_string(), cyclic("b", "a"));
+        descriptor.transforms[2].1.rules[0].conditions_out = vec!["a".to_string()];
+        assert!(matches!(
+            descriptor.to_inflection_rules(),
+            Err(Error::UnknownCondition(_))
+        ));
+
+        let mut descriptor = LanguageTransformDescriptor::from_json(TRANSFORMS).unwrap();
+        descriptor.transforms[0].1.rules[0].kind = "wholeWord".to_string();
+        assert!(matches!(
+            descriptor.to_inflection_rules(),
+            Err(Error::UnsupportedRuleType(kind)) if kind == "wholeWord"
+        ));
+    }
+}
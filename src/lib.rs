@@ -17,51 +17,203 @@
 //!
 //! This library is based on [yomichan's japanese deinflector](https://github.com/FooSoft/yomichan).
 use bitflags::bitflags;
+pub use inflect::{
+    conjugate, inflect, inflection_chains, ConjugationTable, InflectionChain, InflectionChains,
+};
+pub use normalize::{normalize, variants, Normalization, Normalized, Variants};
 use once_cell::sync::Lazy;
-pub use rules::INFLECTION_RULES;
+pub use rules::{Reasons, CLASSICAL_RULES, COLLOQUIAL_RULES, INFLECTION_RULES, KANSAI_RULES};
+use std::borrow::Cow;
 
+mod inflect;
+#[cfg(feature = "serde")]
+pub mod json;
+mod normalize;
 mod rules;
 
-static LOOKUP_TREE: Lazy<Tree<char, Info>> = Lazy::new(|| {
-    let mut tree = Tree::new();
-    for InflectionRules { reason, rules } in INFLECTION_RULES {
-        for rule in rules.iter() {
-            tree.insert(
-                rule.kana_in.chars().rev(),
-                Info {
-                    reason: *reason,
-                    rule,
-                    kana_in_chars: rule.kana_in.chars().count(),
-                    kana_out_chars: rule.kana_out.chars().count(),
-                },
-            );
+static DEFAULT_DEINFLECTOR: Lazy<Deinflector> = Lazy::new(Deinflector::default);
+
+/// Derives deinflections from a set of [`InflectionRules`].
+///
+/// [`Deinflections::from_word`] and [`Deinflections::from_str`] use a shared
+/// deinflector built from [`INFLECTION_RULES`], a `Deinflector` is only needed
+/// for deinflecting with other rules.
+///
+/// ```rust
+/// use deinflect::{r, Deinflector, InflectionRules, Reasons, Rules, INFLECTION_RULES};
+///
+/// let mut deinflector = Deinflector::new(INFLECTION_RULES.iter().cloned());
+/// deinflector.add_rules([InflectionRules {
+///     reason: Reasons::NEGATIVE,
+///     rules: vec![r("らん", "る", Rules::empty(), Rules::V5)].into(),
+/// }]);
+///
+/// let deinflections = deinflector.deinflect("知らん");
+/// assert!(deinflections
+///     .iter()
+///     .any(|d| deinflections.to_string(d) == "知る"));
+/// ```
+///
+/// Rule sets that aren't part of the default rules, like the contractions in
+/// [`COLLOQUIAL_RULES`], classical Japanese in [`CLASSICAL_RULES`] or the
+/// Kansai dialect in [`KANSAI_RULES`], are added the same way with
+/// `deinflector.add_rules(COLLOQUIAL_RULES.iter().cloned())`.
+pub struct Deinflector {
+    rules: Vec<InflectionRules>,
+    tree: Tree<char, Info>,
+    normalization: Normalization,
+    variants: Variants,
+}
+
+impl Default for Deinflector {
+    fn default() -> Self {
+        Self::new(INFLECTION_RULES.iter().cloned())
+    }
+}
+
+impl Deinflector {
+    pub fn new(rules: impl IntoIterator<Item = InflectionRules>) -> Self {
+        let mut this = Self {
+            rules: Vec::new(),
+            tree: Tree::new(),
+            normalization: Normalization::empty(),
+            variants: Variants::empty(),
+        };
+        this.add_rules(rules);
+        this
+    }
+
+    /// Add more rules to the deinflector.
+    pub fn add_rules(&mut self, rules: impl IntoIterator<Item = InflectionRules>) {
+        for inflection_rules in rules {
+            let i = self.rules.len();
+            for (j, rule) in inflection_rules.rules.iter().enumerate() {
+                self.tree.insert(
+                    rule.kana_in.chars().rev(),
+                    Info {
+                        reason: inflection_rules.reason,
+                        rules_in: rule.rules_in,
+                        id: RuleId {
+                            inflection_rules: i,
+                            rule: j,
+                        },
+                        kana_in_chars: rule.kana_in.chars().count(),
+                        kana_out_chars: rule.kana_out.chars().count(),
+                    },
+                );
+            }
+            self.rules.push(inflection_rules);
         }
     }
-    tree
-});
 
-#[derive(Debug, Clone)]
-pub struct Deinflections<'a> {
-    source: &'a str,
-    deinflections: Vec<DeinflectionMeta>,
-}
+    /// The rules used by the deinflector, [`RuleId`]s index into this slice.
+    pub fn rules(&self) -> &[InflectionRules] {
+        &self.rules
+    }
+
+    /// Normalize words before deinflecting them, e.g. to deinflect katakana
+    /// as if it was written in hiragana. No normalization is applied by
+    /// default.
+    ///
+    /// The deinflections are derived from the normalized word,
+    /// [`Deinflections::original`] gives the part of the input they
+    /// correspond to.
+    ///
+    /// ```rust
+    /// use deinflect::{Deinflector, Normalization};
+    ///
+    /// let mut deinflector = Deinflector::default();
+    /// deinflector.set_normalization(Normalization::all());
+    ///
+    /// let deinflections = deinflector.deinflect("ｷｶﾚﾏｼﾀ");
+    /// assert!(deinflections
+    ///     .iter()
+    ///     .any(|d| deinflections.to_string(d) == "きく"));
+    /// ```
+    pub fn set_normalization(&mut self, normalization: Normalization) {
+        self.normalization = normalization;
+    }
+
+    pub fn normalization(&self) -> Normalization {
+        self.normalization
+    }
+
+    /// Also deinflect alternative spellings of words that are common in
+    /// casual text, such as すごーい for すごい, see [`variants`]. None are
+    /// generated by default.
+    ///
+    /// Deinflections of a variant have the applied variants in
+    /// [`DeinflectionMeta::variants`].
+    ///
+    /// ```rust
+    /// use deinflect::{Deinflector, Variants};
+    ///
+    /// let mut deinflector = Deinflector::default();
+    /// deinflector.set_variants(Variants::all());
+    ///
+    /// let deinflections = deinflector.deinflect("すっごーい");
+    /// assert!(deinflections.iter().any(|d| {
+    ///     deinflections.to_string(d) == "すごい"
+    ///         && deinflections.meta(d).variants == Variants::SOKUON | Variants::LONG_VOWEL_MARK
+    /// }));
+    /// ```
+    pub fn set_variants(&mut self, variants: Variants) {
+        self.variants = variants;
+    }
+
+    pub fn variants(&self) -> Variants {
+        self.variants
+    }
 
-impl<'a> Deinflections<'a> {
     /// Derive all possible deinflections for the given word.
     ///
     /// The deinflections are not guaranteed to be valid japanese words,
     /// use a dictionary to filter out invalid words.
-    pub fn from_word(word: &'a str) -> Self {
-        let mut this = Self {
-            source: word,
-            deinflections: vec![DeinflectionMeta {
-                source: DeinflectionSource::Original,
-                replace_from_back: 0,
-                replace_with: "",
-                replace_with_chars: 0,
-                rules: Rules::empty(),
-                reasons: Reasons::empty(),
-            }],
+    pub fn deinflect<'a>(&'a self, word: &'a str) -> Deinflections<'a> {
+        if self.normalization.is_empty() {
+            self.deinflect_source(Cow::Borrowed(word), word)
+        } else {
+            let Normalized { text, .. } = normalize(word, self.normalization);
+            self.deinflect_source(Cow::Owned(text), word)
+        }
+    }
+
+    fn deinflect_source<'a>(
+        &'a self,
+        source: Cow<'a, str>,
+        original: &'a str,
+    ) -> Deinflections<'a> {
+        let variants = if self.variants.is_empty() {
+            Vec::new()
+        } else {
+            variants(&source, self.variants)
+        };
+
+        let root = DeinflectionMeta {
+            source: DeinflectionSource::Original,
+            replace_from_back: 0,
+            replace_with: "",
+            replace_with_chars: 0,
+            rules: Rules::empty(),
+            reasons: Reasons::empty(),
+            variants: Variants::empty(),
+            step: None,
+        };
+        let mut deinflections = vec![root];
+        for (i, (_, applied)) in variants.iter().enumerate() {
+            deinflections.push(DeinflectionMeta {
+                source: DeinflectionSource::Variant(i),
+                variants: *applied,
+                ..root
+            });
+        }
+
+        let mut this = Deinflections {
+            source,
+            original,
+            variants,
+            prefixed: Vec::new(),
+            deinflections,
         };
 
         let mut i = 0;
@@ -71,27 +223,97 @@ impl<'a> Deinflections<'a> {
         while i < this.deinflections.len() {
             let prev = this.deinflections[i];
             let chars_rev = this.chars_rev(Deinflection(i));
+            // the length in chars, only counted for rules that need it
+            let mut len = None;
 
             for Info {
                 reason,
-                rule,
+                rules_in,
+                id,
                 kana_in_chars,
                 kana_out_chars,
-            } in LOOKUP_TREE.get_submatches(chars_rev)
+            } in self.tree.get_submatches(chars_rev)
             {
-                if prev.rules.is_empty() || prev.rules.intersects(rule.rules_in) {
-                    buffer.push(DeinflectionMeta {
+                if prev.rules.is_empty() || prev.rules.intersects(*rules_in) {
+                    let inflection_rules = &self.rules[id.inflection_rules];
+                    let rule = &inflection_rules.rules[id.rule];
+                    // a rule can't remove the whole word, like ながら without
+                    // the verb in front of it
+                    if rule.kana_out.is_empty()
+                        && rule.prefix_out.is_empty()
+                        && *kana_in_chars
+                            == *len.get_or_insert_with(|| this.chars_rev(Deinflection(i)).count())
+                    {
+                        continue;
+                    }
+                    let mut meta = DeinflectionMeta {
                         source: DeinflectionSource::Deinflection(i),
                         replace_from_back: *kana_in_chars,
                         replace_with: &rule.kana_out,
                         replace_with_chars: *kana_out_chars,
                         rules: rule.rules_out,
                         reasons: prev.reasons | *reason,
-                    });
+                        variants: prev.variants,
+                        step: Some(Step {
+                            reason: *reason,
+                            rule,
+                            inflection_rules,
+                            id: *id,
+                        }),
+                    };
+
+                    if rule.prefix_in.is_empty() {
+                        buffer.push((meta, None));
+                        continue;
+                    }
+
+                    // the tree only matched the suffix, the prefix is
+                    // checked by walking the characters of the current word
+                    // again, which is linear in its length. Only rules that
+                    // match store the whole deinflected word.
+                    let len = *len.get_or_insert_with(|| this.chars_rev(Deinflection(i)).count());
+                    let prefix_chars = rule.prefix_in.chars().count();
+                    // the prefix and the suffix can't overlap
+                    let Some(stem_chars) = len.checked_sub(prefix_chars + kana_in_chars) else {
+                        continue;
+                    };
+                    // a rule that only removes the prefix needs a stem
+                    if stem_chars == 0 && rule.prefix_out.is_empty() && !rule.kana_in.is_empty() {
+                        continue;
+                    }
+                    let starts_with_prefix = this
+                        .chars_rev(Deinflection(i))
+                        .skip(len - prefix_chars)
+                        .eq(rule.prefix_in.chars().rev());
+                    if !starts_with_prefix {
+                        continue;
+                    }
+
+                    let stem: Vec<char> = this
+                        .chars_rev(Deinflection(i))
+                        .skip(*kana_in_chars)
+                        .take(stem_chars)
+                        .collect();
+                    let mut deinflected = rule.prefix_out.to_string();
+                    deinflected.extend(stem.iter().rev());
+                    deinflected.push_str(&rule.kana_out);
+                    meta.replace_from_back = 0;
+                    meta.replace_with = "";
+                    meta.replace_with_chars = 0;
+                    buffer.push((meta, Some(deinflected)));
                 }
             }
 
-            this.deinflections.append(&mut buffer);
+            for (mut meta, word) in buffer.drain(..) {
+                if let Some(word) = word {
+                    meta.source = DeinflectionSource::Prefix {
+                        deinflection: i,
+                        word: this.prefixed.len(),
+                    };
+                    this.prefixed.push(word);
+                }
+                this.deinflections.push(meta);
+            }
 
             i += 1;
         }
@@ -103,7 +325,24 @@ impl<'a> Deinflections<'a> {
     /// is processed by removing one character at a time from the back and
     /// checking for deinflections of the remaining string. Each element of
     /// the returned vector corresponds to one more character removed.
-    pub fn from_str(s: &'a str) -> Vec<Deinflections> {
+    pub fn deinflect_str<'a>(&'a self, s: &'a str) -> Vec<Deinflections<'a>> {
+        if !self.normalization.is_empty() {
+            let Normalized { text, offsets } = normalize(s, self.normalization);
+            let ends = text
+                .char_indices()
+                .map(|(i, c)| i + c.len_utf8())
+                .collect::<Vec<_>>();
+            return ends
+                .iter()
+                .enumerate()
+                .rev()
+                .map(|(n, &end)| {
+                    let source = Cow::Owned(text[..end].to_string());
+                    self.deinflect_source(source, &s[..offsets[n + 1]])
+                })
+                .collect();
+        }
+
         s.chars()
             .rev()
             .scan(0, |i, c| {
@@ -111,13 +350,47 @@ impl<'a> Deinflections<'a> {
                 *i += c.len_utf8();
                 Some(s)
             })
-            .map(Self::from_word)
+            .map(|s| self.deinflect(s))
             .collect()
     }
+}
+
+#[derive(Debug, Clone)]
+pub struct Deinflections<'a> {
+    source: Cow<'a, str>,
+    original: &'a str,
+    variants: Vec<(String, Variants)>,
+    /// The words of deinflections by rules with a prefix, see
+    /// [`DeinflectionSource::Prefix`].
+    prefixed: Vec<String>,
+    deinflections: Vec<DeinflectionMeta<'a>>,
+}
+
+impl<'a> Deinflections<'a> {
+    /// Derive all possible deinflections for the given word using
+    /// [`INFLECTION_RULES`], see [`Deinflector::deinflect`].
+    pub fn from_word(word: &'a str) -> Self {
+        DEFAULT_DEINFLECTOR.deinflect(word)
+    }
+
+    /// Derive all possible deinflections for the given string using
+    /// [`INFLECTION_RULES`], see [`Deinflector::deinflect_str`].
+    #[allow(clippy::should_implement_trait)]
+    pub fn from_str(s: &'a str) -> Vec<Deinflections<'a>> {
+        DEFAULT_DEINFLECTOR.deinflect_str(s)
+    }
+
+    /// The part of the input the deinflections were derived from. This is
+    /// the word itself unless the word was normalized, see
+    /// [`Deinflector::set_normalization`].
+    pub fn original(&self) -> &'a str {
+        self.original
+    }
 
     /// Get the characters of the deinflected word in reverse order.
     pub fn chars_rev(&self, deinflection: Deinflection) -> impl Iterator<Item = char> + '_ {
         let mut data = &self.deinflections[deinflection.0];
+        #[allow(clippy::iter_skip_zero)]
         let mut chars = data.replace_with.chars().rev().skip(0);
         let mut carry_over_replace_from_back = 0;
         let mut processing_original = false;
@@ -140,6 +413,23 @@ impl<'a> Deinflections<'a> {
                                 .skip(data.replace_from_back + carry_over_replace_from_back);
                             continue;
                         }
+                        DeinflectionSource::Variant(i) => {
+                            processing_original = true;
+                            chars = self.variants[i]
+                                .0
+                                .chars()
+                                .rev()
+                                .skip(data.replace_from_back + carry_over_replace_from_back);
+                            continue;
+                        }
+                        DeinflectionSource::Prefix { word, .. } => {
+                            processing_original = true;
+                            chars = self.prefixed[word]
+                                .chars()
+                                .rev()
+                                .skip(data.replace_from_back + carry_over_replace_from_back);
+                            continue;
+                        }
                         DeinflectionSource::Deinflection(i) => {
                             let replace = data.replace_from_back + carry_over_replace_from_back;
                             data = &self.deinflections[i];
@@ -166,8 +456,34 @@ impl<'a> Deinflections<'a> {
             .collect()
     }
 
+    /// Get the chain of rules that were applied to the original word to arrive
+    /// at the deinflection.
+    ///
+    /// The steps are yielded starting from the rule closest to the deinflected
+    /// word, so for 聞く derived from 聞かれました the iterator yields passive
+    /// first and polite past second. Unlike [`DeinflectionMeta::reasons`] this
+    /// preserves the order of the reasons and reasons that were applied more
+    /// than once.
+    pub fn steps(
+        &self,
+        deinflection: Deinflection,
+    ) -> impl Iterator<Item = (Reasons, &'a RuleInfo)> + '_ {
+        let mut data = &self.deinflections[deinflection.0];
+        std::iter::from_fn(move || {
+            let step = data.step?;
+            if let DeinflectionSource::Deinflection(i)
+            | DeinflectionSource::Prefix {
+                deinflection: i, ..
+            } = data.source
+            {
+                data = &self.deinflections[i];
+            }
+            Some((step.reason, step.rule))
+        })
+    }
+
     /// Get more information about the deinflection.
-    pub fn meta(&self, deinflection: Deinflection) -> &DeinflectionMeta {
+    pub fn meta(&self, deinflection: Deinflection) -> &DeinflectionMeta<'a> {
         &self.deinflections[deinflection.0]
     }
 
@@ -182,19 +498,54 @@ impl<'a> Deinflections<'a> {
 }
 
 #[derive(Debug, Clone, Copy)]
-pub struct DeinflectionMeta {
+pub struct DeinflectionMeta<'a> {
     pub source: DeinflectionSource,
     pub replace_from_back: usize,
-    pub replace_with: &'static str,
+    pub replace_with: &'a str,
     pub replace_with_chars: usize,
     pub rules: Rules,
     pub reasons: Reasons,
+    /// The spelling variants applied to the original word before
+    /// deinflecting, see [`Deinflector::set_variants`].
+    pub variants: Variants,
+    /// The rule applied to the source to arrive at this deinflection,
+    /// `None` for the original word.
+    pub step: Option<Step<'a>>,
+}
+
+/// A single rule application, see [`Deinflections::steps`].
+#[derive(Debug, Clone, Copy)]
+pub struct Step<'a> {
+    pub reason: Reasons,
+    pub rule: &'a RuleInfo,
+    /// The entry of [`Deinflector::rules`] the rule belongs to.
+    pub inflection_rules: &'a InflectionRules,
+    pub id: RuleId,
+}
+
+/// Identifies a rule by its position in [`Deinflector::rules`], i.e. the rule
+/// is `deinflector.rules()[id.inflection_rules].rules[id.rule]`. For
+/// [`Deinflections::from_word`] this is the position in [`INFLECTION_RULES`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RuleId {
+    pub inflection_rules: usize,
+    pub rule: usize,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub enum DeinflectionSource {
     Original,
+    /// A spelling variant of the original word, see [`Deinflector::set_variants`].
+    Variant(usize),
     Deinflection(usize),
+    /// The deinflection by a rule with a [`RuleInfo::prefix_in`]. The whole
+    /// word is stored instead of the replacement at the back, so
+    /// `replace_from_back` and `replace_with` of the deinflection are empty
+    /// and deinflections derived from it replace the back of that word.
+    Prefix {
+        deinflection: usize,
+        word: usize,
+    },
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -288,58 +639,22 @@ impl<K: Ord + Copy, T> Node<K, T> {
 #[derive(Clone, Copy)]
 struct Info {
     reason: Reasons,
-    rule: &'static RuleInfo,
+    rules_in: Rules,
+    id: RuleId,
     kana_in_chars: usize,
     kana_out_chars: usize,
 }
 
 bitflags! {
+    /// The word classes a rule applies to, like [`Reasons`] human readable
+    /// formats serialize the names of the flags.
+    ///
+    /// Binary formats like bincode or postcard serialize the bits, since
+    /// version 0.2 as a `u32` instead of a `u8`, so data written by earlier
+    /// versions can't be read back.
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
     #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
-    pub struct Reasons: u64 {
-        const BA = 1;
-        const CHAU = 1 << 1;
-        const CHIMAU = 1 << 2;
-        const SHIMAU = 1 << 3;
-        const NASAI = 1 << 4;
-        const SOU = 1 << 5;
-        const SUGIRU = 1 << 6;
-        const TAI = 1 << 7;
-        const TARA = 1 << 8;
-        const TARI = 1 << 9;
-        const TE = 1 << 10;
-        const ZU = 1 << 11;
-        const NU = 1 << 12;
-        const ADV = 1 << 13;
-        const CAUSATIVE = 1 << 14;
-        const IMPERATIVE = 1 << 15;
-        const IMPERATIVE_NEGATIVE = 1 << 16;
-        const MASU_STEM = 1 << 17;
-        const NEGATIVE = 1 << 18;
-        const NOUN = 1 << 19;
-        const PASSIVE = 1 << 20;
-        const PAST = 1 << 21;
-        const POLITE = 1 << 22;
-        const POLITE_NEGATIVE = 1 << 23;
-        const POLITE_PAST = 1 << 24;
-        const POLITE_PAST_NEGATIVE = 1 << 25;
-        const POLITE_VOLITIONAL = 1 << 26;
-        const POTENTIAL = 1 << 27;
-        const POTENTIAL_OR_PASSIVE = 1 << 28;
-        const VOLITIONAL = 1 << 29;
-        const CAUSATIVE_PASSIVE = 1 << 30;
-        const TOKU = 1 << 31;
-        const PROGRESSIVE_OR_PERFECT = 1 << 32;
-        const KI = 1 << 33;
-        const GE = 1 << 34;
-        const E = 1 << 35;
-    }
-}
-
-bitflags! {
-    #[derive(Debug, Clone, Copy)]
-    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
-    pub struct Rules: u8 {
+    pub struct Rules: u32 {
         const V1 = 1;   // Verb ichidan
         const V5 = 1 << 1;   // Verb godan
         const VS = 1 << 2;   // Verb suru
@@ -347,33 +662,94 @@ bitflags! {
         const VZ = 1 << 4;   // Verb zuru
         const ADJ_I = 1 << 5; // Adjective i
         const IRU = 1 << 6;  // In
+        const ADJ_NA = 1 << 7; // Adjective na
+        const COP = 1 << 8;  // Copula
+        const V5K_S = 1 << 9; // Verb godan 行く
+        const V5R_I = 1 << 10; // Verb godan ある
+        const V5ARU = 1 << 11; // Verb godan なさる, いらっしゃる
+        const V5U_S = 1 << 12; // Verb godan 問う
+        const V5S = 1 << 13; // Verb godan ending in す, before the short causative 書かす
+        const PAST = 1 << 14; // Past form, before らしい and っぽい
+        const MASU_STEM = 1 << 15; // Masu stem, before ながら and つつ
     }
 }
 
 // The following structs are used for storing deflection rules directly
 // in rust, see rules.rs
+#[derive(Debug, Clone)]
 pub struct InflectionRules {
     pub reason: Reasons,
-    pub rules: &'static [RuleInfo],
+    pub rules: Cow<'static, [RuleInfo]>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct RuleInfo {
-    pub kana_in: &'static str,
-    pub kana_out: &'static str,
+    /// The start of the inflected word, like the お of お読みになる. It is
+    /// replaced with `prefix_out` along with `kana_in`, which makes the rule
+    /// only apply to words starting with it.
+    pub prefix_in: Cow<'static, str>,
+    /// The start of the deinflected word, like the 見 of 見る for ご覧になる.
+    pub prefix_out: Cow<'static, str>,
+    pub kana_in: Cow<'static, str>,
+    pub kana_out: Cow<'static, str>,
     pub rules_in: Rules,
     pub rules_out: Rules,
 }
 
+impl RuleInfo {
+    pub fn new(
+        kana_in: impl Into<Cow<'static, str>>,
+        kana_out: impl Into<Cow<'static, str>>,
+        rules_in: Rules,
+        rules_out: Rules,
+    ) -> Self {
+        Self {
+            prefix_in: Cow::Borrowed(""),
+            prefix_out: Cow::Borrowed(""),
+            kana_in: kana_in.into(),
+            kana_out: kana_out.into(),
+            rules_in,
+            rules_out,
+        }
+    }
+
+    /// Set the [`prefix_in`](RuleInfo::prefix_in) and
+    /// [`prefix_out`](RuleInfo::prefix_out) of the rule.
+    pub fn with_prefix(
+        mut self,
+        prefix_in: impl Into<Cow<'static, str>>,
+        prefix_out: impl Into<Cow<'static, str>>,
+    ) -> Self {
+        self.prefix_in = prefix_in.into();
+        self.prefix_out = prefix_out.into();
+        self
+    }
+}
+
 pub const fn r(
     kana_in: &'static str,
     kana_out: &'static str,
     rules_in: Rules,
     rules_out: Rules,
+) -> RuleInfo {
+    rp("", "", kana_in, kana_out, rules_in, rules_out)
+}
+
+/// [`r`] for rules with a [`prefix_in`](RuleInfo::prefix_in) and
+/// [`prefix_out`](RuleInfo::prefix_out).
+pub const fn rp(
+    prefix_in: &'static str,
+    prefix_out: &'static str,
+    kana_in: &'static str,
+    kana_out: &'static str,
+    rules_in: Rules,
+    rules_out: Rules,
 ) -> RuleInfo {
     RuleInfo {
-        kana_in,
-        kana_out,
+        prefix_in: Cow::Borrowed(prefix_in),
+        prefix_out: Cow::Borrowed(prefix_out),
+        kana_in: Cow::Borrowed(kana_in),
+        kana_out: Cow::Borrowed(kana_out),
         rules_in,
         rules_out,
     }
@@ -409,13 +785,18 @@ mod tests {
                 replace_with_chars: replace_with.chars().count(),
                 rules: Rules::empty(),
                 reasons: Reasons::empty(),
+                variants: Variants::empty(),
+                step: None,
             });
             deinflection
         }
 
         // simple replacement
         let mut ds = Deinflections {
-            source: "abc",
+            source: "abc".into(),
+            original: "abc",
+            variants: Vec::new(),
+            prefixed: Vec::new(),
             deinflections: Vec::new(),
         };
         let d1 = push(&mut ds, "de", 1, DeinflectionSource::Original);
@@ -423,7 +804,10 @@ mod tests {
 
         // replace all
         let mut ds = Deinflections {
-            source: "abc",
+            source: "abc".into(),
+            original: "abc",
+            variants: Vec::new(),
+            prefixed: Vec::new(),
             deinflections: Vec::new(),
         };
         let d1 = push(&mut ds, "de", 4, DeinflectionSource::Original);
@@ -431,7 +815,10 @@ mod tests {
 
         // carry over replacement to source
         let mut ds = Deinflections {
-            source: "abc",
+            source: "abc".into(),
+            original: "abc",
+            variants: Vec::new(),
+            prefixed: Vec::new(),
             deinflections: Vec::new(),
         };
         let d1 = push(&mut ds, "de", 1, DeinflectionSource::Original);
@@ -441,7 +828,10 @@ mod tests {
 
         // carry over replacement to second last deinflection
         let mut ds = Deinflections {
-            source: "abc",
+            source: "abc".into(),
+            original: "abc",
+            variants: Vec::new(),
+            prefixed: Vec::new(),
             deinflections: Vec::new(),
         };
         let d1 = push(&mut ds, "de", 1, DeinflectionSource::Original);
@@ -453,7 +843,10 @@ mod tests {
 
         // carry over replacement to second last deinflection and then source
         let mut ds = Deinflections {
-            source: "abc",
+            source: "abc".into(),
+            original: "abc",
+            variants: Vec::new(),
+            prefixed: Vec::new(),
             deinflections: Vec::new(),
         };
         let d1 = push(&mut ds, "de", 1, DeinflectionSource::Original);
@@ -462,6 +855,31 @@ mod tests {
         assert_eq!("abde", ds.to_string(d1).as_str());
         assert_eq!("abdfg", ds.to_string(d2).as_str());
         assert_eq!("ah", ds.to_string(d3).as_str());
+
+        // replaced prefix, further replacements stop at the prefixed word
+        let mut ds = Deinflections {
+            source: "abc".into(),
+            original: "abc",
+            variants: Vec::new(),
+            prefixed: vec!["xbd".to_string()],
+            deinflections: Vec::new(),
+        };
+        let d1 = push(&mut ds, "d", 1, DeinflectionSource::Original);
+        let d2 = push(
+            &mut ds,
+            "",
+            0,
+            DeinflectionSource::Prefix {
+                deinflection: d1.0,
+                word: 0,
+            },
+        );
+        let d3 = push(&mut ds, "ef", 1, DeinflectionSource::Deinflection(d2.0));
+        let d4 = push(&mut ds, "g", 3, DeinflectionSource::Deinflection(d3.0));
+        assert_eq!("abd", ds.to_string(d1).as_str());
+        assert_eq!("xbd", ds.to_string(d2).as_str());
+        assert_eq!("xbef", ds.to_string(d3).as_str());
+        assert_eq!("xg", ds.to_string(d4).as_str());
     }
 
     fn assert_includes(deinflections: &[Deinflections], s: impl AsRef<str>) {
@@ -479,6 +897,562 @@ mod tests {
         assert_includes(&d, "聞く");
     }
 
+    #[test]
+    fn deinflection_steps() {
+        let deinflector = Deinflector::default();
+        let d = deinflector.deinflect("聞かれました");
+
+        let steps = d
+            .iter()
+            .filter(|&f| d.to_string(f) == "聞く")
+            .map(|f| d.steps(f).map(|(reason, _)| reason).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        assert!(steps.contains(&vec![Reasons::PASSIVE, Reasons::POLITE_PAST]));
+
+        for f in d.iter() {
+            let meta = d.meta(f);
+            assert_eq!(
+                d.steps(f).fold(Reasons::empty(), |acc, (r, _)| acc | r),
+                meta.reasons
+            );
+            match meta.step {
+                Some(step) => {
+                    assert_eq!(d.steps(f).next().unwrap().0, step.reason);
+                    let inflection_rules = &deinflector.rules()[step.id.inflection_rules];
+                    assert!(std::ptr::eq(inflection_rules, step.inflection_rules));
+                    assert!(std::ptr::eq(
+                        &inflection_rules.rules[step.id.rule],
+                        step.rule
+                    ));
+                    assert_eq!(inflection_rules.reason, step.reason);
+                }
+                None => assert!(d.steps(f).next().is_none()),
+            }
+        }
+    }
+
+    #[test]
+    fn rule_ids_are_stable() {
+        // new rules are appended, so the ids of existing rules don't change
+        let reasons = INFLECTION_RULES
+            .iter()
+            .map(|rules| rules.reason)
+            .take(36)
+            .collect::<Vec<_>>();
+        assert_eq!(
+            reasons,
+            [
+                Reasons::POLITE_PAST_NEGATIVE,
+                Reasons::TAI,
+                Reasons::PASSIVE,
+                Reasons::TOKU,
+                Reasons::GE,
+                Reasons::NASAI,
+                Reasons::PAST,
+                Reasons::TARI,
+                Reasons::VOLITIONAL,
+                Reasons::CHAU,
+                Reasons::TE,
+                Reasons::MASU_STEM,
+                Reasons::POLITE_NEGATIVE,
+                Reasons::BA,
+                Reasons::POTENTIAL,
+                Reasons::PROGRESSIVE_OR_PERFECT,
+                Reasons::IMPERATIVE_NEGATIVE,
+                Reasons::CHIMAU,
+                Reasons::SUGIRU,
+                Reasons::NEGATIVE,
+                Reasons::TARA,
+                Reasons::POLITE_VOLITIONAL,
+                Reasons::ZU,
+                Reasons::CAUSATIVE,
+                Reasons::POLITE,
+                Reasons::KI,
+                Reasons::SOU,
+                Reasons::IMPERATIVE,
+                Reasons::E,
+                Reasons::NU,
+                Reasons::POTENTIAL_OR_PASSIVE,
+                Reasons::ADV,
+                Reasons::NOUN,
+                Reasons::POLITE_PAST,
+                Reasons::CAUSATIVE_PASSIVE,
+                Reasons::SHIMAU,
+            ]
+        );
+        let rule = &INFLECTION_RULES[0].rules[1];
+        assert_eq!((&*rule.kana_in, &*rule.kana_out), ("ませんでした", "る"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn flags_serialize_as_names() {
+        let reasons = Reasons::NEGATIVE | Reasons::PAST;
+        let json = serde_json::to_string(&reasons).unwrap();
+        assert_eq!(json, r#""NEGATIVE | PAST""#);
+        assert_eq!(serde_json::from_str::<Reasons>(&json).unwrap(), reasons);
+
+        let rules = Rules::V5 | Rules::V5K_S;
+        let json = serde_json::to_string(&rules).unwrap();
+        assert_eq!(json, r#""V5 | V5K_S""#);
+        assert_eq!(serde_json::from_str::<Rules>(&json).unwrap(), rules);
+    }
+
+    /// Assert that each word has the term as a candidate that matches.
+    fn assert_candidates<T: Copy + std::fmt::Debug>(
+        deinflector: &Deinflector,
+        cases: &[(&str, &str, T)],
+        matches: impl Fn(&DeinflectionMeta, T) -> bool,
+    ) {
+        for &(word, term, expected) in cases {
+            let d = deinflector.deinflect(word);
+            assert!(
+                d.iter()
+                    .any(|f| d.to_string(f) == term && matches(d.meta(f), expected)),
+                "{word} does not have term candidate {term} with {expected:?}"
+            );
+        }
+    }
+
+    /// Assert that each word has the term as a candidate of the word class
+    /// with exactly the reasons.
+    fn assert_deinflects(deinflector: &Deinflector, cases: &[(&str, &str, Rules, Reasons)]) {
+        let cases: Vec<_> = cases
+            .iter()
+            .map(|&(word, term, rules, reasons)| (word, term, (rules, reasons)))
+            .collect();
+        assert_candidates(deinflector, &cases, |meta, (rules, reasons)| {
+            meta.rules.intersects(rules) && meta.reasons == reasons
+        });
+    }
+
+    #[test]
+    fn colloquial_rules() {
+        let d = DEFAULT_DEINFLECTOR.deinflect("行かなきゃ");
+        assert!(!d.iter().any(|f| d.to_string(f) == "行く"));
+
+        let mut deinflector = Deinflector::default();
+        deinflector.add_rules(COLLOQUIAL_RULES.iter().cloned());
+
+        let cases = [
+            (
+                "行かなきゃ",
+                "行く",
+                Rules::V5,
+                Reasons::NAKYA | Reasons::NEGATIVE,
+            ),
+            (
+                "行かなけりゃ",
+                "行く",
+                Rules::V5,
+                Reasons::NAKYA | Reasons::NEGATIVE,
+            ),
+            (
+                "食べなくちゃ",
+                "食べる",
+                Rules::V1,
+                Reasons::NAKUCHA | Reasons::NEGATIVE,
+            ),
+            ("見ちゃ", "見る", Rules::V1, Reasons::CHA | Reasons::TE),
+            ("読んじゃ", "読む", Rules::V5, Reasons::CHA | Reasons::TE),
+            (
+                "見ちゃだめ",
+                "見る",
+                Rules::V1,
+                Reasons::CHA | Reasons::PROHIBITION | Reasons::TE,
+            ),
+            (
+                "行っちゃいけない",
+                "行く",
+                Rules::V5K_S,
+                Reasons::CHA | Reasons::PROHIBITION | Reasons::TE,
+            ),
+            (
+                "飲んじゃいけません",
+                "飲む",
+                Rules::V5,
+                Reasons::CHA | Reasons::PROHIBITION | Reasons::TE,
+            ),
+            (
+                "読んでる",
+                "読む",
+                Rules::V5,
+                Reasons::PROGRESSIVE_OR_PERFECT | Reasons::TE,
+            ),
+            (
+                "分かんない",
+                "分かる",
+                Rules::V5,
+                Reasons::N | Reasons::NEGATIVE,
+            ),
+            (
+                "分かんなかった",
+                "分かる",
+                Rules::V5,
+                Reasons::N | Reasons::NEGATIVE | Reasons::PAST,
+            ),
+            ("知らん", "知る", Rules::V5, Reasons::N),
+            ("行かん", "行く", Rules::V5K_S, Reasons::N),
+            ("食べん", "食べる", Rules::V1, Reasons::N),
+            ("せん", "する", Rules::VS, Reasons::N),
+            ("来ん", "来る", Rules::VK, Reasons::N),
+        ];
+        assert_deinflects(&deinflector, &cases);
+        // -n only applies to negative stems
+        let d = deinflector.deinflect("みかん");
+        assert!(!d
+            .iter()
+            .any(|f| d.meta(f).reasons.contains(Reasons::N)
+                && d.meta(f).rules.intersects(Rules::ADJ_I)));
+        assert!(!d.iter().any(|f| d.to_string(f) == "みかない"));
+        // or after a stem that can't be ichidan
+        assert!(!d.iter().any(|f| d.to_string(f) == "みかる"));
+
+        assert!(Reasons::COLLOQUIAL.contains(Reasons::NAKYA | Reasons::N));
+        assert!(!Reasons::COLLOQUIAL.intersects(Reasons::NEGATIVE));
+    }
+
+    #[test]
+    fn classical_rules() {
+        let mut deinflector = Deinflector::default();
+        deinflector.add_rules(CLASSICAL_RULES.iter().cloned());
+
+        let cases = [
+            ("行かざる", "行く", Rules::V5K_S, Reasons::ZARU),
+            ("行くべし", "行く", Rules::V5K_S, Reasons::BESHI),
+            ("すべし", "する", Rules::VS, Reasons::BESHI),
+            ("勉強するべし", "勉強する", Rules::VS, Reasons::BESHI),
+            ("行くがごとし", "行く", Rules::V5K_S, Reasons::GOTOSHI),
+            ("書けり", "書く", Rules::V5, Reasons::PERFECTIVE),
+            ("書きたり", "書く", Rules::V5, Reasons::PERFECTIVE),
+            ("決せり", "決する", Rules::VS, Reasons::PERFECTIVE),
+            ("ありけり", "ある", Rules::V5R_I, Reasons::KERI),
+            ("行かむ", "行く", Rules::V5K_S, Reasons::MU),
+            ("散りぬれば", "散る", Rules::V5, Reasons::NUREBA),
+            ("高し", "高い", Rules::ADJ_I, Reasons::SHI),
+            ("美し", "美しい", Rules::ADJ_I, Reasons::SHI),
+            ("美しき", "美しい", Rules::ADJ_I, Reasons::KI),
+            ("多かる", "多い", Rules::ADJ_I, Reasons::KARI),
+            (
+                "高からず",
+                "高い",
+                Rules::ADJ_I,
+                Reasons::KARI | Reasons::ZU,
+            ),
+        ];
+        assert_deinflects(&deinflector, &cases);
+        // べき is in the default rules, the classical ones don't repeat it
+        let d = deinflector.deinflect("すべき");
+        assert!(d
+            .iter()
+            .filter(|&f| d.to_string(f) == "する")
+            .all(|f| d.meta(f).reasons == Reasons::OBLIGATION));
+        assert!(!d
+            .iter()
+            .any(|f| d.to_string(f) == "す" && d.meta(f).rules.intersects(Rules::VS)));
+        // nor the -tari forms that look like the perfective たり
+        let d = deinflector.deinflect("食べたり");
+        assert!(d
+            .iter()
+            .filter(|&f| d.to_string(f) == "食べる")
+            .all(|f| d.meta(f).reasons == Reasons::TARI));
+
+        assert!(Reasons::CLASSICAL.contains(Reasons::BESHI | Reasons::KARI));
+        assert!(!Reasons::CLASSICAL.intersects(Reasons::ZU | Reasons::COLLOQUIAL));
+    }
+
+    #[test]
+    fn kansai_rules() {
+        let mut deinflector = Deinflector::default();
+        deinflector.add_rules(KANSAI_RULES.iter().cloned());
+
+        let cases = [
+            ("行かへん", "行く", Rules::V5K_S, Reasons::KANSAI_NEGATIVE),
+            ("行けへん", "行く", Rules::V5K_S, Reasons::KANSAI_NEGATIVE),
+            ("あらへん", "ある", Rules::V5R_I, Reasons::KANSAI_NEGATIVE),
+            ("食べへん", "食べる", Rules::V1, Reasons::KANSAI_NEGATIVE),
+            ("見いひん", "見る", Rules::V1, Reasons::KANSAI_NEGATIVE),
+            ("せえへん", "する", Rules::VS, Reasons::KANSAI_NEGATIVE),
+            ("けえへん", "くる", Rules::VK, Reasons::KANSAI_NEGATIVE),
+            (
+                "行かへんかった",
+                "行く",
+                Rules::V5K_S,
+                Reasons::KANSAI_NEGATIVE_PAST,
+            ),
+            ("行かはる", "行く", Rules::V5K_S, Reasons::KANSAI_HONORIFIC),
+            (
+                "行きはります",
+                "行く",
+                Rules::V5K_S,
+                Reasons::KANSAI_HONORIFIC | Reasons::POLITE,
+            ),
+            (
+                "読んどる",
+                "読む",
+                Rules::V5,
+                Reasons::KANSAI_PROGRESSIVE | Reasons::PROGRESSIVE_OR_PERFECT | Reasons::TE,
+            ),
+            (
+                "読んどった",
+                "読む",
+                Rules::V5,
+                Reasons::PAST
+                    | Reasons::KANSAI_PROGRESSIVE
+                    | Reasons::PROGRESSIVE_OR_PERFECT
+                    | Reasons::TE,
+            ),
+            (
+                "静かや",
+                "静か",
+                Rules::ADJ_NA,
+                Reasons::KANSAI_COPULA | Reasons::COPULA,
+            ),
+            (
+                "静かやった",
+                "静か",
+                Rules::ADJ_NA,
+                Reasons::KANSAI_COPULA | Reasons::PAST | Reasons::COPULA,
+            ),
+        ];
+        assert_deinflects(&deinflector, &cases);
+        assert!(Reasons::KANSAI.contains(Reasons::KANSAI_NEGATIVE | Reasons::KANSAI_COPULA));
+
+        let d = DEFAULT_DEINFLECTOR.deinflect("行かへん");
+        assert!(!d.iter().any(|f| d.to_string(f) == "行く"));
+    }
+
+    #[test]
+    fn prefix_rules() {
+        let d = Deinflections::from_word("お待たせになりました");
+        let f = d
+            .iter()
+            .find(|&f| d.to_string(f) == "待つ")
+            .expect("お待たせになりました deinflects to 待つ");
+
+        let chars: String = d.chars_rev(f).collect();
+        assert_eq!(chars, "つ待");
+        let steps: Vec<_> = d.steps(f).map(|(reason, _)| reason).collect();
+        assert_eq!(
+            steps,
+            [Reasons::CAUSATIVE, Reasons::HONORIFIC, Reasons::POLITE_PAST]
+        );
+
+        // the prefix is replaced as well
+        let d = Deinflections::from_word("ご覧になりました");
+        let f = d
+            .iter()
+            .find(|&f| d.to_string(f) == "見る")
+            .expect("ご覧になりました deinflects to 見る");
+        assert_eq!(d.chars_rev(f).collect::<String>(), "る見");
+
+        // the prefix has to be at the start of the word
+        let d = Deinflections::from_word("読みになる");
+        assert!(!d.iter().any(|f| d.to_string(f) == "読む"));
+        // and can't overlap the suffix
+        let d = Deinflections::from_word("おになる");
+        assert!(!d.iter().any(|f| d.to_string(f) == "する"));
+    }
+
+    #[test]
+    fn prefix_rules_need_a_stem() {
+        let deinflector = Deinflector::new([InflectionRules {
+            reason: Reasons::HONORIFIC,
+            rules: vec![
+                rp("お", "", "になる", "る", Rules::empty(), Rules::V1),
+                rp("ご覧", "見", "になる", "る", Rules::empty(), Rules::V1),
+            ]
+            .into(),
+        }]);
+        let candidates = |word| {
+            let d = deinflector.deinflect(word);
+            d.iter().skip(1).map(|f| d.to_string(f)).collect::<Vec<_>>()
+        };
+
+        assert_eq!(candidates("お見になる"), ["見る"]);
+        // removing only the prefix would leave just the ending
+        assert!(candidates("おになる").is_empty());
+        // unless the prefix is replaced
+        assert_eq!(candidates("ご覧になる"), ["見る"]);
+        assert!(candidates("ご覧なる").is_empty());
+    }
+
+    #[test]
+    fn deinflector_with_owned_rules() {
+        let mut deinflector = Deinflector::new([]);
+        assert!(deinflector.deinflect("行かなきゃ").iter().count() == 1);
+
+        deinflector.add_rules(INFLECTION_RULES.iter().cloned());
+        deinflector.add_rules([InflectionRules {
+            reason: Reasons::NEGATIVE,
+            rules: vec![RuleInfo::new(
+                String::from("なきゃ"),
+                String::from("ない"),
+                Rules::empty(),
+                Rules::ADJ_I,
+            )]
+            .into(),
+        }]);
+
+        let d = deinflector.deinflect("行かなきゃ");
+        assert!(d.iter().any(|f| d.to_string(f) == "行かない"
+            && d.meta(f).step.unwrap().id.inflection_rules == INFLECTION_RULES.len()));
+        assert!(d
+            .iter()
+            .any(|f| d.to_string(f) == "行く" && d.meta(f).rules.intersects(Rules::V5)));
+    }
+
+    #[test]
+    fn normalized_deinflections() {
+        let mut deinflector = Deinflector::default();
+        let d = deinflector.deinflect("キカレマシタ");
+        assert!(!d.iter().any(|f| d.to_string(f) == "きく"));
+
+        deinflector.set_normalization(Normalization::all());
+        for word in ["キカレマシタ", "ｷｶﾚﾏｼﾀ", "聞カレマシタ"] {
+            let d = deinflector.deinflect(word);
+            assert_eq!(d.original(), word);
+            assert!(d
+                .iter()
+                .any(|f| d.to_string(f).ends_with("く")
+                    && d.meta(f).reasons.contains(Reasons::PASSIVE)));
+        }
+
+        let ds = deinflector.deinflect_str("ﾀﾍﾞﾏｼﾀﾖ");
+        assert_eq!(ds.len(), 6);
+        assert_eq!(ds[0].original(), "ﾀﾍﾞﾏｼﾀﾖ");
+        assert_eq!(ds[1].original(), "ﾀﾍﾞﾏｼﾀ");
+        assert!(ds[1].iter().any(|f| ds[1].to_string(f) == "たべる"));
+        assert_eq!(ds[4].original(), "ﾀﾍﾞ");
+        assert_eq!(ds[5].original(), "ﾀ");
+    }
+
+    #[test]
+    fn variant_deinflections() {
+        let mut deinflector = Deinflector::default();
+        deinflector.set_variants(Variants::all());
+
+        let cases = [
+            ("すごーい", "すごい", Variants::LONG_VOWEL_MARK),
+            ("うるさーい", "うるさい", Variants::LONG_VOWEL_MARK),
+            ("すっごい", "すごい", Variants::SOKUON),
+            ("寒っ", "寒い", Variants::SOKUON),
+            ("すごいいい", "すごい", Variants::REPEATED_VOWEL),
+            (
+                "すっごーく",
+                "すごい",
+                Variants::SOKUON | Variants::LONG_VOWEL_MARK,
+            ),
+            ("寒かったーー", "寒い", Variants::LONG_VOWEL_MARK),
+        ];
+        assert_candidates(&deinflector, &cases, |meta, variants| {
+            meta.variants == variants
+        });
+
+        let d = deinflector.deinflect("食べた");
+        assert!(d
+            .iter()
+            .any(|f| d.to_string(f) == "食べる" && d.meta(f).variants.is_empty()));
+    }
+
+    #[test]
+    fn conjunctive_rules_need_a_verb() {
+        for word in ["ながら", "つつ"] {
+            let d = Deinflections::from_word(word);
+            assert!(d.iter().all(|f| !d.to_string(f).is_empty()), "{word}");
+        }
+
+        // the stem keeps the class of the verb
+        let d = Deinflections::from_word("食べたながら");
+        assert!(!d.iter().any(|f| d.to_string(f) == "食べる"));
+    }
+
+    #[test]
+    fn auxiliary_adjectives_follow_the_past() {
+        let d = Deinflections::from_word("食べたらしい");
+        let f = d
+            .iter()
+            .find(|&f| d.to_string(f) == "食べる")
+            .expect("食べたらしい deinflects to 食べる");
+        assert_eq!(d.meta(f).reasons, Reasons::RASHII | Reasons::PAST);
+        let d = Deinflections::from_word("高かったっぽい");
+        assert!(d.iter().any(|f| d.to_string(f) == "高い"));
+
+        // nouns that merely end like a suffix
+        let d = Deinflections::from_word("上がる");
+        assert!(!d.iter().any(|f| d.to_string(f) == "上"));
+        let d = Deinflections::from_word("好み");
+        assert!(!d
+            .iter()
+            .any(|f| d.to_string(f) == "好" || d.to_string(f) == "好い"));
+    }
+
+    #[test]
+    fn auxiliaries_are_one_step() {
+        let cases = [
+            (
+                "行ってはいけない",
+                "行く",
+                [Reasons::TE, Reasons::PROHIBITION],
+            ),
+            (
+                "行ってはいけません",
+                "行く",
+                [Reasons::TE, Reasons::PROHIBITION],
+            ),
+            (
+                "読んでも構わない",
+                "読む",
+                [Reasons::TE, Reasons::PERMISSION],
+            ),
+            (
+                "読んでも構いません",
+                "読む",
+                [Reasons::TE, Reasons::PERMISSION],
+            ),
+            ("待ってください", "待つ", [Reasons::TE, Reasons::REQUEST]),
+            (
+                "行かなければならない",
+                "行く",
+                [Reasons::NEGATIVE, Reasons::OBLIGATION],
+            ),
+            (
+                "来なくても構わない",
+                "来る",
+                [Reasons::NEGATIVE, Reasons::NO_OBLIGATION],
+            ),
+        ];
+        let negatives =
+            |steps: &[Reasons]| steps.iter().filter(|&&r| r == Reasons::NEGATIVE).count();
+        for (word, term, steps) in cases {
+            let d = Deinflections::from_word(word);
+            let found: Vec<_> = d
+                .iter()
+                .filter(|&f| d.to_string(f) == term)
+                .map(|f| d.steps(f).map(|(reason, _)| reason).collect::<Vec<_>>())
+                .collect();
+            assert!(found.contains(&steps.to_vec()), "{word}");
+            // the negative of いけない or 構わない isn't a step of its own
+            assert!(
+                found.iter().all(|s| negatives(s) == negatives(&steps)),
+                "{word}"
+            );
+        }
+    }
+
+    #[test]
+    fn short_causative_is_not_ambiguous() {
+        // the passive of a short causative is the causative passive
+        let d = Deinflections::from_word("待たされた");
+        assert_eq!(d.iter().filter(|&f| d.to_string(f) == "待つ").count(), 1);
+        let d = Deinflections::from_word("飲まされる");
+        assert_eq!(d.iter().filter(|&f| d.to_string(f) == "飲む").count(), 1);
+
+        // and its potential is the causative
+        let d = Deinflections::from_word("飲ませる");
+        assert_eq!(d.iter().filter(|&f| d.to_string(f) == "飲む").count(), 1);
+    }
+
     struct DeinflectValidTest {
         term: &'static str,
         source: &'static str,
@@ -582,45 +1556,154 @@ mod tests {
                 rule: "adj-i",
                 reasons: vec!["-ge"],
             },
-            // Common verbs
+            // Na-adjective
             DeinflectValidTest {
-                term: "食べる",
-                source: "食べる",
-                rule: "v1",
+                term: "静か",
+                source: "静か",
+                rule: "adj-na",
                 reasons: vec![],
             },
             DeinflectValidTest {
-                term: "食べる",
-                source: "食べます",
-                rule: "v1",
-                reasons: vec!["polite"],
+                term: "静か",
+                source: "静かだ",
+                rule: "adj-na",
+                reasons: vec!["copula"],
             },
             DeinflectValidTest {
-                term: "食べる",
-                source: "食べた",
-                rule: "v1",
-                reasons: vec!["past"],
+                term: "静か",
+                source: "静かな",
+                rule: "adj-na",
+                reasons: vec!["attributive"],
             },
             DeinflectValidTest {
-                term: "食べる",
-                source: "食べました",
-                rule: "v1",
-                reasons: vec!["polite past"],
+                term: "静か",
+                source: "静かに",
+                rule: "adj-na",
+                reasons: vec!["adv"],
             },
             DeinflectValidTest {
-                term: "食べる",
-                source: "食べて",
-                rule: "v1",
-                reasons: vec!["-te"],
+                term: "静か",
+                source: "静かで",
+                rule: "adj-na",
+                reasons: vec!["-te", "copula"],
             },
             DeinflectValidTest {
-                term: "食べる",
-                source: "食べられる",
-                rule: "v1",
-                reasons: vec!["potential or passive"],
+                term: "静か",
+                source: "静かです",
+                rule: "adj-na",
+                reasons: vec!["polite", "copula"],
             },
             DeinflectValidTest {
-                term: "食べる",
+                term: "静か",
+                source: "静かでした",
+                rule: "adj-na",
+                reasons: vec!["polite past", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かだった",
+                rule: "adj-na",
+                reasons: vec!["past", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かだったら",
+                rule: "adj-na",
+                reasons: vec!["-tara", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かなら",
+                rule: "adj-na",
+                reasons: vec!["-ba", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かじゃない",
+                rule: "adj-na",
+                reasons: vec!["negative", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かではない",
+                rule: "adj-na",
+                reasons: vec!["negative", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かじゃなかった",
+                rule: "adj-na",
+                reasons: vec!["past", "negative", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かではなかった",
+                rule: "adj-na",
+                reasons: vec!["past", "negative", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かじゃありません",
+                rule: "adj-na",
+                reasons: vec!["polite negative", "copula"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かではありませんでした",
+                rule: "adj-na",
+                reasons: vec!["polite past negative", "copula"],
+            },
+            DeinflectValidTest {
+                term: "綺麗",
+                source: "綺麗だった",
+                rule: "adj-na",
+                reasons: vec!["past", "copula"],
+            },
+            DeinflectValidTest {
+                term: "綺麗",
+                source: "綺麗じゃなくて",
+                rule: "adj-na",
+                reasons: vec!["-te", "negative", "copula"],
+            },
+            // Common verbs
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べる",
+                rule: "v1",
+                reasons: vec![],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べます",
+                rule: "v1",
+                reasons: vec!["polite"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べた",
+                rule: "v1",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べました",
+                rule: "v1",
+                reasons: vec!["polite past"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べて",
+                rule: "v1",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べられる",
+                rule: "v1",
+                reasons: vec!["potential or passive"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
                 source: "食べられる",
                 rule: "v1",
                 reasons: vec!["potential or passive"],
@@ -1085,7 +2168,7 @@ mod tests {
             DeinflectValidTest {
                 term: "行く",
                 source: "行った",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["past"],
             },
             DeinflectValidTest {
@@ -1097,7 +2180,7 @@ mod tests {
             DeinflectValidTest {
                 term: "行く",
                 source: "行って",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-te"],
             },
             DeinflectValidTest {
@@ -1145,7 +2228,7 @@ mod tests {
             DeinflectValidTest {
                 term: "行く",
                 source: "行かなかった",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["negative", "past"],
             },
             DeinflectValidTest {
@@ -1199,13 +2282,13 @@ mod tests {
             DeinflectValidTest {
                 term: "行く",
                 source: "行っちゃう",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-chau"],
             },
             DeinflectValidTest {
                 term: "行く",
                 source: "行っちまう",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-chimau"],
             },
             DeinflectValidTest {
@@ -1289,31 +2372,31 @@ mod tests {
             DeinflectValidTest {
                 term: "行く",
                 source: "行っている",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-te", "progressive or perfect"],
             },
             DeinflectValidTest {
                 term: "行く",
                 source: "行っておる",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-te", "progressive or perfect"],
             },
             DeinflectValidTest {
                 term: "行く",
                 source: "行ってる",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-te", "progressive or perfect"],
             },
             DeinflectValidTest {
                 term: "行く",
                 source: "行っとる",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-te", "progressive or perfect"],
             },
             DeinflectValidTest {
                 term: "行く",
                 source: "行ってしまう",
-                rule: "v5",
+                rule: "v5k-s",
                 reasons: vec!["-te", "-shimau"],
             },
             DeinflectValidTest {
@@ -4564,310 +5647,1008 @@ mod tests {
                 reasons: vec!["-te"],
             },
             DeinflectValidTest {
-                term: "こう",
-                source: "こうて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "こう",
+                source: "こうて",
+                rule: "v5u-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "そう",
+                source: "そうて",
+                rule: "v5",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "とう",
+                source: "とうて",
+                rule: "v5u-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "請う",
+                source: "請うて",
+                rule: "v5u-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "乞う",
+                source: "乞うて",
+                rule: "v5u-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "恋う",
+                source: "恋うて",
+                rule: "v5u-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "問う",
+                source: "問うて",
+                rule: "v5u-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "負う",
+                source: "負うて",
+                rule: "v5",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "沿う",
+                source: "沿うて",
+                rule: "v5",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "添う",
+                source: "添うて",
+                rule: "v5",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "副う",
+                source: "副うて",
+                rule: "v5",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "厭う",
+                source: "厭うて",
+                rule: "v5",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "おう",
+                source: "おうた",
+                rule: "v5",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "こう",
+                source: "こうた",
+                rule: "v5u-s",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "そう",
+                source: "そうた",
+                rule: "v5",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "とう",
+                source: "とうた",
+                rule: "v5u-s",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "請う",
+                source: "請うた",
+                rule: "v5u-s",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "乞う",
+                source: "乞うた",
+                rule: "v5u-s",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "恋う",
+                source: "恋うた",
+                rule: "v5u-s",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "問う",
+                source: "問うた",
+                rule: "v5u-s",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "負う",
+                source: "負うた",
+                rule: "v5",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "沿う",
+                source: "沿うた",
+                rule: "v5",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "添う",
+                source: "添うた",
+                rule: "v5",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "副う",
+                source: "副うた",
+                rule: "v5",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "厭う",
+                source: "厭うた",
+                rule: "v5",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "おう",
+                source: "おうたら",
+                rule: "v5",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "こう",
+                source: "こうたら",
+                rule: "v5u-s",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "そう",
+                source: "そうたら",
+                rule: "v5",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "とう",
+                source: "とうたら",
+                rule: "v5u-s",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "請う",
+                source: "請うたら",
+                rule: "v5u-s",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "乞う",
+                source: "乞うたら",
+                rule: "v5u-s",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "恋う",
+                source: "恋うたら",
+                rule: "v5u-s",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "問う",
+                source: "問うたら",
+                rule: "v5u-s",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "負う",
+                source: "負うたら",
+                rule: "v5",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "沿う",
+                source: "沿うたら",
+                rule: "v5",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "添う",
+                source: "添うたら",
+                rule: "v5",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "副う",
+                source: "副うたら",
+                rule: "v5",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "厭う",
+                source: "厭うたら",
+                rule: "v5",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "おう",
+                source: "おうたり",
+                rule: "v5",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "こう",
+                source: "こうたり",
+                rule: "v5u-s",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "そう",
+                source: "そうたり",
+                rule: "v5",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "とう",
+                source: "とうたり",
+                rule: "v5u-s",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "請う",
+                source: "請うたり",
+                rule: "v5u-s",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "乞う",
+                source: "乞うたり",
+                rule: "v5u-s",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "恋う",
+                source: "恋うたり",
+                rule: "v5u-s",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "問う",
+                source: "問うたり",
+                rule: "v5u-s",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "負う",
+                source: "負うたり",
+                rule: "v5",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "沿う",
+                source: "沿うたり",
+                rule: "v5",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "添う",
+                source: "添うたり",
+                rule: "v5",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "副う",
+                source: "副うたり",
+                rule: "v5",
+                reasons: vec!["-tari"],
+            },
+            DeinflectValidTest {
+                term: "厭う",
+                source: "厭うたり",
+                rule: "v5",
+                reasons: vec!["-tari"],
+            },
+            // Godan subclasses
+            DeinflectValidTest {
+                term: "行く",
+                source: "行かない",
+                rule: "v5k-s",
+                reasons: vec!["negative"],
+            },
+            DeinflectValidTest {
+                term: "行く",
+                source: "行きます",
+                rule: "v5k-s",
+                reasons: vec!["polite"],
+            },
+            DeinflectValidTest {
+                term: "行く",
+                source: "行って",
+                rule: "v5k-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "行く",
+                source: "行ったら",
+                rule: "v5k-s",
+                reasons: vec!["-tara"],
+            },
+            DeinflectValidTest {
+                term: "ある",
+                source: "ない",
+                rule: "v5r-i",
+                reasons: vec!["negative"],
+            },
+            DeinflectValidTest {
+                term: "ある",
+                source: "なかった",
+                rule: "v5r-i",
+                reasons: vec!["negative", "past"],
+            },
+            DeinflectValidTest {
+                term: "ある",
+                source: "ありません",
+                rule: "v5r-i",
+                reasons: vec!["polite negative"],
+            },
+            DeinflectValidTest {
+                term: "ある",
+                source: "あった",
+                rule: "v5r-i",
+                reasons: vec!["past"],
+            },
+            DeinflectValidTest {
+                term: "なさる",
+                source: "なさい",
+                rule: "v5aru",
+                reasons: vec!["imperative"],
+            },
+            DeinflectValidTest {
+                term: "なさる",
+                source: "なさいます",
+                rule: "v5aru",
+                reasons: vec!["polite"],
+            },
+            DeinflectValidTest {
+                term: "なさる",
+                source: "なさらない",
+                rule: "v5aru",
+                reasons: vec!["negative"],
+            },
+            DeinflectValidTest {
+                term: "いらっしゃる",
+                source: "いらっしゃい",
+                rule: "v5aru",
+                reasons: vec!["imperative"],
+            },
+            DeinflectValidTest {
+                term: "いらっしゃる",
+                source: "いらっしゃいました",
+                rule: "v5aru",
+                reasons: vec!["polite past"],
+            },
+            DeinflectValidTest {
+                term: "くださる",
+                source: "ください",
+                rule: "v5aru",
+                reasons: vec!["imperative"],
+            },
+            DeinflectValidTest {
+                term: "ござる",
+                source: "ございます",
+                rule: "v5aru",
+                reasons: vec!["polite"],
+            },
+            DeinflectValidTest {
+                term: "問う",
+                source: "問うて",
+                rule: "v5u-s",
+                reasons: vec!["-te"],
+            },
+            DeinflectValidTest {
+                term: "問う",
+                source: "問わない",
+                rule: "v5u-s",
+                reasons: vec!["negative"],
+            },
+            // Desiderative, appearance and nominalizing suffixes
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べたがる",
+                rule: "v1",
+                reasons: vec!["-tagaru"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べたがっている",
+                rule: "v1",
+                reasons: vec!["-tagaru", "-te", "progressive or perfect"],
+            },
+            DeinflectValidTest {
+                term: "行く",
+                source: "行きたがらない",
+                rule: "v5",
+                reasons: vec!["-tagaru", "negative"],
+            },
+            DeinflectValidTest {
+                term: "する",
+                source: "したがった",
+                rule: "vs",
+                reasons: vec!["-tagaru", "past"],
+            },
+            DeinflectValidTest {
+                term: "嬉しい",
+                source: "嬉しがる",
+                rule: "adj-i",
+                reasons: vec!["-garu"],
+            },
+            DeinflectValidTest {
+                term: "痛い",
+                source: "痛がっている",
+                rule: "adj-i",
+                reasons: vec!["-garu", "-te", "progressive or perfect"],
+            },
+            DeinflectValidTest {
+                term: "寂しい",
+                source: "寂しさ",
+                rule: "adj-i",
+                reasons: vec!["noun"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かさ",
+                rule: "adj-na",
+                reasons: vec!["noun"],
+            },
+            DeinflectValidTest {
+                term: "忘れる",
+                source: "忘れっぽい",
+                rule: "v1",
+                reasons: vec!["-ppoi"],
+            },
+            DeinflectValidTest {
+                term: "怒る",
+                source: "怒りっぽい",
+                rule: "v5",
+                reasons: vec!["-ppoi"],
+            },
+            DeinflectValidTest {
+                term: "安い",
+                source: "安っぽい",
+                rule: "adj-i",
+                reasons: vec!["-ppoi"],
+            },
+            DeinflectValidTest {
+                term: "子供",
+                source: "子供っぽかった",
+                rule: "adj-na",
+                reasons: vec!["-ppoi", "past"],
+            },
+            DeinflectValidTest {
+                term: "行く",
+                source: "行くらしい",
+                rule: "v5",
+                reasons: vec!["-rashii"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べないらしい",
+                rule: "v1",
+                reasons: vec!["negative", "-rashii"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静からしかった",
+                rule: "adj-na",
+                reasons: vec!["-rashii", "past"],
+            },
+            DeinflectValidTest {
+                term: "忘れる",
+                source: "忘れがち",
+                rule: "v1",
+                reasons: vec!["-gachi"],
+            },
+            DeinflectValidTest {
+                term: "遅れる",
+                source: "遅れがちだ",
+                rule: "v1",
+                reasons: vec!["-gachi", "copula"],
+            },
+            DeinflectValidTest {
+                term: "病気",
+                source: "病気がちな",
+                rule: "adj-na",
+                reasons: vec!["-gachi", "attributive"],
+            },
+            // Conjunctive and concessive forms
+            DeinflectValidTest {
+                term: "歩く",
+                source: "歩きながら",
+                rule: "v5",
+                reasons: vec!["-nagara", "masu stem"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べながら",
+                rule: "v1",
+                reasons: vec!["-nagara", "masu stem"],
+            },
+            DeinflectValidTest {
+                term: "思う",
+                source: "思いつつ",
+                rule: "v5",
+                reasons: vec!["-tsutsu", "masu stem"],
+            },
+            DeinflectValidTest {
+                term: "勉強する",
+                source: "勉強しながら",
+                rule: "vs",
+                reasons: vec!["-nagara", "masu stem"],
+            },
+            DeinflectValidTest {
+                term: "来る",
+                source: "来ながら",
+                rule: "vk",
+                reasons: vec!["-nagara", "masu stem"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べても",
+                rule: "v1",
+                reasons: vec!["-te", "-temo"],
+            },
+            DeinflectValidTest {
+                term: "読む",
+                source: "読んでも",
+                rule: "v5",
+                reasons: vec!["-te", "-temo"],
+            },
+            DeinflectValidTest {
+                term: "高い",
+                source: "高くても",
+                rule: "adj-i",
+                reasons: vec!["-te", "-temo"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かでも",
+                rule: "adj-na",
+                reasons: vec!["-temo", "copula"],
+            },
+            DeinflectValidTest {
+                term: "行く",
+                source: "行っては",
+                rule: "v5k-s",
+                reasons: vec!["-te", "-tewa"],
+            },
+            DeinflectValidTest {
+                term: "読む",
+                source: "読んでは",
+                rule: "v5",
+                reasons: vec!["-te", "-tewa"],
+            },
+            DeinflectValidTest {
+                term: "言う",
+                source: "言ったって",
+                rule: "v5",
+                reasons: vec!["-te", "-tatte"],
+            },
+            DeinflectValidTest {
+                term: "する",
+                source: "したって",
+                rule: "vs",
+                reasons: vec!["-te", "-tatte"],
+            },
+            DeinflectValidTest {
+                term: "高い",
+                source: "高くたって",
+                rule: "adj-i",
+                reasons: vec!["-te", "-tatte"],
+            },
+            // Auxiliary verbs after the te-form
+            DeinflectValidTest {
+                term: "読む",
+                source: "読んでおく",
+                rule: "v5",
+                reasons: vec!["-te", "-oku"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べてみた",
+                rule: "v1",
+                reasons: vec!["-te", "-miru", "past"],
+            },
+            DeinflectValidTest {
+                term: "読む",
+                source: "読んでみます",
+                rule: "v5",
+                reasons: vec!["-te", "-miru", "polite"],
+            },
+            DeinflectValidTest {
+                term: "書く",
+                source: "書いてある",
+                rule: "v5",
+                reasons: vec!["-te", "-aru"],
+            },
+            DeinflectValidTest {
+                term: "書く",
+                source: "書いてあった",
+                rule: "v5",
+                reasons: vec!["-te", "-aru", "past"],
+            },
+            DeinflectValidTest {
+                term: "持つ",
+                source: "持ってきて",
+                rule: "v5",
+                reasons: vec!["-te", "-kuru"],
+            },
+            DeinflectValidTest {
+                term: "走る",
+                source: "走っていく",
+                rule: "v5",
+                reasons: vec!["-te", "-iku"],
+            },
+            DeinflectValidTest {
+                term: "変わる",
+                source: "変わって行った",
+                rule: "v5",
+                reasons: vec!["-te", "-iku", "past"],
+            },
+            DeinflectValidTest {
+                term: "買う",
+                source: "買ってあげる",
+                rule: "v5",
+                reasons: vec!["-te", "-ageru"],
+            },
+            DeinflectValidTest {
+                term: "来る",
+                source: "来てくれる",
+                rule: "vk",
+                reasons: vec!["-te", "-kureru"],
+            },
+            DeinflectValidTest {
+                term: "教える",
+                source: "教えてもらいました",
+                rule: "v1",
+                reasons: vec!["-te", "-morau", "polite past"],
+            },
+            // Obligation, permission and prohibition
+            DeinflectValidTest {
+                term: "行く",
+                source: "行かなければならない",
+                rule: "v5k-s",
+                reasons: vec!["obligation", "negative"],
+            },
+            DeinflectValidTest {
+                term: "食べる",
+                source: "食べなくてはいけない",
+                rule: "v1",
+                reasons: vec!["obligation", "negative"],
+            },
+            DeinflectValidTest {
+                term: "書く",
+                source: "書かないといけなかった",
+                rule: "v5",
+                reasons: vec!["obligation", "negative", "past"],
+            },
+            DeinflectValidTest {
+                term: "静か",
+                source: "静かでなければならない",
+                rule: "adj-na",
+                reasons: vec!["obligation", "negative", "copula"],
             },
             DeinflectValidTest {
-                term: "そう",
-                source: "そうて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "行く",
+                source: "行くべきだ",
+                rule: "v5k-s",
+                reasons: vec!["obligation", "copula"],
             },
             DeinflectValidTest {
-                term: "とう",
-                source: "とうて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "する",
+                source: "すべき",
+                rule: "vs",
+                reasons: vec!["obligation"],
             },
             DeinflectValidTest {
-                term: "請う",
-                source: "請うて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "食べる",
+                source: "食べてもいい",
+                rule: "v1",
+                reasons: vec!["permission", "-te"],
             },
             DeinflectValidTest {
-                term: "乞う",
-                source: "乞うて",
+                term: "読む",
+                source: "読んでも構わない",
                 rule: "v5",
-                reasons: vec!["-te"],
+                reasons: vec!["permission", "-te"],
             },
             DeinflectValidTest {
-                term: "恋う",
-                source: "恋うて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "行く",
+                source: "行ってはいけない",
+                rule: "v5k-s",
+                reasons: vec!["prohibition", "-te"],
             },
             DeinflectValidTest {
-                term: "問う",
-                source: "問うて",
+                term: "触る",
+                source: "触ってはだめだ",
                 rule: "v5",
-                reasons: vec!["-te"],
+                reasons: vec!["prohibition", "copula", "-te"],
             },
             DeinflectValidTest {
-                term: "負う",
-                source: "負うて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "来る",
+                source: "来なくてもいい",
+                rule: "vk",
+                reasons: vec!["no obligation", "negative"],
             },
             DeinflectValidTest {
-                term: "沿う",
-                source: "沿うて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "高い",
+                source: "高くなくてもいい",
+                rule: "adj-i",
+                reasons: vec!["no obligation", "negative"],
             },
             DeinflectValidTest {
-                term: "添う",
-                source: "添うて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "行く",
+                source: "行かなければなりません",
+                rule: "v5k-s",
+                reasons: vec!["obligation", "negative"],
             },
             DeinflectValidTest {
-                term: "副う",
-                source: "副うて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "行く",
+                source: "行かなければなりませんでした",
+                rule: "v5k-s",
+                reasons: vec!["obligation", "negative"],
             },
             DeinflectValidTest {
-                term: "厭う",
-                source: "厭うて",
-                rule: "v5",
-                reasons: vec!["-te"],
+                term: "食べる",
+                source: "食べなければならなかった",
+                rule: "v1",
+                reasons: vec!["obligation", "negative", "past"],
             },
             DeinflectValidTest {
-                term: "おう",
-                source: "おうた",
+                term: "読む",
+                source: "読んでも構いません",
                 rule: "v5",
-                reasons: vec!["past"],
+                reasons: vec!["permission", "-te"],
             },
             DeinflectValidTest {
-                term: "こう",
-                source: "こうた",
-                rule: "v5",
-                reasons: vec!["past"],
+                term: "行く",
+                source: "行ってはいけません",
+                rule: "v5k-s",
+                reasons: vec!["prohibition", "-te"],
             },
             DeinflectValidTest {
-                term: "そう",
-                source: "そうた",
-                rule: "v5",
-                reasons: vec!["past"],
+                term: "勉強する",
+                source: "勉強するべき",
+                rule: "vs",
+                reasons: vec!["obligation"],
             },
+            // Requests
             DeinflectValidTest {
-                term: "とう",
-                source: "とうた",
+                term: "待つ",
+                source: "待ってください",
                 rule: "v5",
-                reasons: vec!["past"],
+                reasons: vec!["request", "-te"],
             },
             DeinflectValidTest {
-                term: "請う",
-                source: "請うた",
+                term: "読む",
+                source: "読んでくださいませ",
                 rule: "v5",
-                reasons: vec!["past"],
+                reasons: vec!["request", "-te"],
             },
             DeinflectValidTest {
-                term: "乞う",
-                source: "乞うた",
-                rule: "v5",
-                reasons: vec!["past"],
+                term: "見る",
+                source: "見てくれ",
+                rule: "v1",
+                reasons: vec!["request", "-te"],
             },
             DeinflectValidTest {
-                term: "恋う",
-                source: "恋うた",
+                term: "貸す",
+                source: "貸してちょうだい",
                 rule: "v5",
-                reasons: vec!["past"],
+                reasons: vec!["request", "-te"],
             },
             DeinflectValidTest {
-                term: "問う",
-                source: "問うた",
-                rule: "v5",
-                reasons: vec!["past"],
+                term: "する",
+                source: "して下さい",
+                rule: "vs",
+                reasons: vec!["request", "-te"],
             },
             DeinflectValidTest {
-                term: "負う",
-                source: "負うた",
-                rule: "v5",
-                reasons: vec!["past"],
+                term: "忘れる",
+                source: "忘れないでください",
+                rule: "v1",
+                reasons: vec!["negative request", "negative"],
             },
             DeinflectValidTest {
-                term: "沿う",
-                source: "沿うた",
-                rule: "v5",
-                reasons: vec!["past"],
+                term: "行く",
+                source: "行かないでくれ",
+                rule: "v5k-s",
+                reasons: vec!["negative request", "negative"],
             },
             DeinflectValidTest {
-                term: "添う",
-                source: "添うた",
+                term: "休む",
+                source: "休みなさいませ",
                 rule: "v5",
-                reasons: vec!["past"],
+                reasons: vec!["-nasai"],
             },
+            // Short causative
             DeinflectValidTest {
-                term: "副う",
-                source: "副うた",
+                term: "書く",
+                source: "書かす",
                 rule: "v5",
-                reasons: vec!["past"],
+                reasons: vec!["short causative"],
             },
             DeinflectValidTest {
-                term: "厭う",
-                source: "厭うた",
+                term: "書く",
+                source: "書かさない",
                 rule: "v5",
-                reasons: vec!["past"],
+                reasons: vec!["short causative", "negative"],
             },
             DeinflectValidTest {
-                term: "おう",
-                source: "おうたら",
+                term: "飲む",
+                source: "飲ます",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["short causative"],
             },
             DeinflectValidTest {
-                term: "こう",
-                source: "こうたら",
+                term: "飲む",
+                source: "飲ますべき",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["short causative", "obligation"],
             },
             DeinflectValidTest {
-                term: "そう",
-                source: "そうたら",
-                rule: "v5",
-                reasons: vec!["-tara"],
+                term: "食べる",
+                source: "食べさす",
+                rule: "v1",
+                reasons: vec!["short causative"],
             },
             DeinflectValidTest {
-                term: "とう",
-                source: "とうたら",
+                term: "話す",
+                source: "話さす",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["short causative"],
             },
             DeinflectValidTest {
-                term: "請う",
-                source: "請うたら",
-                rule: "v5",
-                reasons: vec!["-tara"],
+                term: "勉強する",
+                source: "勉強さす",
+                rule: "vs",
+                reasons: vec!["short causative"],
             },
             DeinflectValidTest {
-                term: "乞う",
-                source: "乞うたら",
-                rule: "v5",
-                reasons: vec!["-tara"],
+                term: "来る",
+                source: "来さす",
+                rule: "vk",
+                reasons: vec!["short causative"],
             },
             DeinflectValidTest {
-                term: "恋う",
-                source: "恋うたら",
+                term: "書く",
+                source: "書かしました",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["short causative", "polite past"],
             },
             DeinflectValidTest {
-                term: "問う",
-                source: "問うたら",
+                term: "待つ",
+                source: "待たさなかった",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["short causative", "negative", "past"],
             },
             DeinflectValidTest {
-                term: "負う",
-                source: "負うたら",
+                term: "書く",
+                source: "書かせられる",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["causative", "potential or passive"],
             },
             DeinflectValidTest {
-                term: "沿う",
-                source: "沿うたら",
+                term: "書く",
+                source: "書かされる",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["causative passive"],
             },
             DeinflectValidTest {
-                term: "添う",
-                source: "添うたら",
+                term: "待つ",
+                source: "待たされた",
                 rule: "v5",
-                reasons: vec!["-tara"],
+                reasons: vec!["causative passive", "past"],
             },
             DeinflectValidTest {
-                term: "副う",
-                source: "副うたら",
-                rule: "v5",
-                reasons: vec!["-tara"],
+                term: "食べる",
+                source: "食べさされる",
+                rule: "v1",
+                reasons: vec!["causative passive"],
             },
             DeinflectValidTest {
-                term: "厭う",
-                source: "厭うたら",
-                rule: "v5",
-                reasons: vec!["-tara"],
+                term: "来る",
+                source: "来さされました",
+                rule: "vk",
+                reasons: vec!["causative passive", "polite past"],
             },
+            // Keigo
             DeinflectValidTest {
-                term: "おう",
-                source: "おうたり",
+                term: "読む",
+                source: "お読みになる",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["honorific"],
             },
             DeinflectValidTest {
-                term: "こう",
-                source: "こうたり",
+                term: "読む",
+                source: "お読みになります",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["polite", "honorific"],
             },
             DeinflectValidTest {
-                term: "そう",
-                source: "そうたり",
+                term: "待つ",
+                source: "お待ちください",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["imperative", "honorific"],
             },
             DeinflectValidTest {
-                term: "とう",
-                source: "とうたり",
+                term: "待つ",
+                source: "お待ちくださいました",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["polite past", "honorific"],
             },
             DeinflectValidTest {
-                term: "請う",
-                source: "請うたり",
-                rule: "v5",
-                reasons: vec!["-tari"],
+                term: "見る",
+                source: "ご覧になります",
+                rule: "v1",
+                reasons: vec!["polite", "honorific"],
             },
             DeinflectValidTest {
-                term: "乞う",
-                source: "乞うたり",
-                rule: "v5",
-                reasons: vec!["-tari"],
+                term: "見る",
+                source: "ご覧ください",
+                rule: "v1",
+                reasons: vec!["imperative", "honorific"],
             },
             DeinflectValidTest {
-                term: "恋う",
-                source: "恋うたり",
-                rule: "v5",
-                reasons: vec!["-tari"],
+                term: "説明する",
+                source: "ご説明になる",
+                rule: "vs",
+                reasons: vec!["honorific"],
             },
             DeinflectValidTest {
-                term: "問う",
-                source: "問うたり",
+                term: "待つ",
+                source: "お待ちなさい",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["imperative", "honorific"],
             },
             DeinflectValidTest {
-                term: "負う",
-                source: "負うたり",
+                term: "持つ",
+                source: "お持ちします",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["polite", "humble"],
             },
             DeinflectValidTest {
-                term: "沿う",
-                source: "沿うたり",
-                rule: "v5",
-                reasons: vec!["-tari"],
+                term: "案内する",
+                source: "ご案内いたします",
+                rule: "vs",
+                reasons: vec!["polite", "humble"],
             },
             DeinflectValidTest {
-                term: "添う",
-                source: "添うたり",
-                rule: "v5",
-                reasons: vec!["-tari"],
+                term: "説明する",
+                source: "説明させていただきます",
+                rule: "vs",
+                reasons: vec!["polite", "humble"],
             },
             DeinflectValidTest {
-                term: "副う",
-                source: "副うたり",
+                term: "読む",
+                source: "読ませていただきます",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["polite", "humble"],
             },
             DeinflectValidTest {
-                term: "厭う",
-                source: "厭うたり",
+                term: "食べる",
+                source: "食べさせていただきました",
+                rule: "v1",
+                reasons: vec!["polite past", "humble"],
+            },
+            DeinflectValidTest {
+                term: "待つ",
+                source: "お待たせになる",
                 rule: "v5",
-                reasons: vec!["-tari"],
+                reasons: vec!["honorific", "causative"],
             },
             // Combinations
             DeinflectValidTest {
@@ -5096,6 +6877,31 @@ mod tests {
     #[test]
     fn invalid_cases() {
         let cases = vec![
+            DeinflectInvalidTest {
+                term: "行く",
+                source: "行いた",
+                rule: "v5k-s",
+            },
+            DeinflectInvalidTest {
+                term: "書く",
+                source: "書った",
+                rule: "v5",
+            },
+            DeinflectInvalidTest {
+                term: "ある",
+                source: "あらない",
+                rule: "v5r-i",
+            },
+            DeinflectInvalidTest {
+                term: "なさる",
+                source: "なされ",
+                rule: "v5aru",
+            },
+            DeinflectInvalidTest {
+                term: "問う",
+                source: "問った",
+                rule: "v5u-s",
+            },
             DeinflectInvalidTest {
                 term: "する",
                 source: "すます",
@@ -5305,79 +7111,70 @@ mod tests {
             );
         }
     }
-}
 
-// #[test]
-// #[allow(dead_code)]
-// fn convert_deflection_json_to_rust() {
-//     use std::collections::HashMap;
-
-//     #[derive(serde::Deserialize)]
-//     #[serde(rename_all = "camelCase")]
-//     struct RuleJson {
-//         kana_in: String,
-//         kana_out: String,
-//         rules_in: Vec<String>,
-//         rules_out: Vec<String>,
-//     }
-
-//     let rules: HashMap<String, Vec<RuleJson>> =
-//         serde_json::from_str(include_str!("./deinflect.json")).unwrap();
-
-//     let mut out = "// Generated by mod.rs in deinflect\n\n\
-//         use super::{r, Reasons, Rules, InflectionRules};\n\n\
-//         pub const INFLECTION_RULES: &[InflectionRules] = &[\n"
-//         .to_string();
-
-//     for (reason, rules) in rules.iter() {
-//         let reason = reason
-//             .trim_start_matches("-")
-//             .replace(" ", "_")
-//             .to_uppercase();
-
-//         let mut rules_string = String::new();
-//         for rule in rules {
-//             fn rules_to_string(rules: &[String]) -> String {
-//                 if rules.is_empty() {
-//                     "Rules::empty()".to_string()
-//                 } else if rules.len() == 1 {
-//                     "Rules::".to_string() + &rules[0].replace("-", "_").to_uppercase()
-//                 } else {
-//                     let rules = rules
-//                         .iter()
-//                         .map(|rule| {
-//                             "Rules::".to_string()
-//                                 + &rule.replace("-", "_").to_uppercase()
-//                                 + ".bits()"
-//                         })
-//                         .collect::<Vec<_>>()
-//                         .join(" | ");
-//                     format!("Rules::from_bits_retain({})", rules)
-//                 }
-//             }
-
-//             let rules_in = rules_to_string(&rule.rules_in);
-//             let rules_out = rules_to_string(&rule.rules_out);
-
-//             rules_string += &format!(
-//                 "r(\"{}\", \"{}\", {}, {}),\n",
-//                 rule.kana_in, rule.kana_out, rules_in, rules_out
-//             );
-//         }
-
-//         out += &format!(
-//             r#"InflectionRules {{
-//                 reason: Reasons::{},
-//                 rules: &[
-//                     {}
-//                 ]
-//             }},
-//             "#,
-//             reason, rules_string
-//         );
-//     }
-
-//     out += "];";
-
-//     std::fs::write("./rules.rs", out).unwrap();
-// }
+    const LEMMAS: &[(&str, Rules)] = &[
+        ("食べる", Rules::V1),
+        ("見る", Rules::V1),
+        ("書く", Rules::V5),
+        ("泳ぐ", Rules::V5),
+        ("話す", Rules::V5),
+        ("待つ", Rules::V5),
+        ("死ぬ", Rules::V5),
+        ("遊ぶ", Rules::V5),
+        ("読む", Rules::V5),
+        ("買う", Rules::V5),
+        ("帰る", Rules::V5),
+        ("行く", Rules::V5K_S),
+        ("ある", Rules::V5R_I),
+        ("なさる", Rules::V5ARU),
+        ("いらっしゃる", Rules::V5ARU),
+        ("問う", Rules::V5U_S),
+        ("する", Rules::VS),
+        ("勉強する", Rules::VS),
+        ("来る", Rules::VK),
+        ("くる", Rules::VK),
+        ("信ずる", Rules::VZ),
+        ("高い", Rules::ADJ_I),
+        ("美しい", Rules::ADJ_I),
+        ("静か", Rules::ADJ_NA),
+        ("綺麗", Rules::ADJ_NA),
+    ];
+
+    static CONJUGATIONS: Lazy<Vec<InflectionChains>> = Lazy::new(|| {
+        LEMMAS
+            .iter()
+            .map(|&(word, rules)| inflection_chains(word, rules, Reasons::all(), 2))
+            .collect()
+    });
+
+    proptest::proptest! {
+        #![proptest_config(proptest::prelude::ProptestConfig::with_cases(128))]
+
+        #[test]
+        fn inflections_deinflect_to_lemma(
+            lemma in 0..LEMMAS.len(),
+            conjugation in proptest::prelude::any::<proptest::sample::Index>(),
+            form in proptest::prelude::any::<proptest::sample::Index>(),
+        ) {
+            let (word, rules) = LEMMAS[lemma];
+            let conjugations = CONJUGATIONS[lemma].iter().collect::<Vec<_>>();
+            let conjugation = conjugation.get(&conjugations);
+            let form = form.get(&conjugation.forms);
+
+            let d = Deinflections::from_word(form);
+            let found = d.iter().any(|f| {
+                d.to_string(f) == word
+                    && (conjugation.reasons.is_empty() || d.meta(f).rules.intersects(rules))
+                    && d.steps(f).map(|(reason, _)| reason).eq(conjugation.reasons.iter().copied())
+            });
+            proptest::prop_assert!(
+                found,
+                "{} does not deinflect to {} with {:?} and {:?}",
+                form,
+                word,
+                rules,
+                conjugation.reasons
+            );
+        }
+    }
+}
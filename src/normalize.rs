@@ -0,0 +1,290 @@
+use bitflags::bitflags;
+
+bitflags! {
+    /// Normalizations applied to words before deinflecting them, see
+    /// [`Deinflector::set_normalization`](crate::Deinflector::set_normalization).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+    pub struct Normalization: u8 {
+        /// キカレマシタ -> きかれました
+        const KATAKANA = 1;
+        /// ｷｶﾚﾏｼﾀ -> キカレマシタ, combining ﾞ and ﾟ with the preceding kana
+        const HALF_WIDTH_KANA = 1 << 1;
+        /// Ｎｏ．１ -> No.1
+        const FULL_WIDTH_ASCII = 1 << 2;
+    }
+}
+
+bitflags! {
+    /// Alternative spellings of casual and emphatic speech that are
+    /// deinflected in addition to the word itself, see
+    /// [`Deinflector::set_variants`](crate::Deinflector::set_variants).
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+    pub struct Variants: u8 {
+        /// すごーい -> すごい
+        const LONG_VOWEL_MARK = 1;
+        /// すごいいい -> すごい, かわいいいい -> かわいい
+        const REPEATED_VOWEL = 1 << 1;
+        /// すっごい -> すごい, 寒っ -> 寒い
+        const SOKUON = 1 << 2;
+    }
+}
+
+/// A normalized string together with the positions of its characters in the
+/// string it was normalized from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Normalized {
+    pub text: String,
+    /// The byte offset in the original string of each character of `text`,
+    /// followed by the length of the original string. The first `n`
+    /// characters of `text` were normalized from `original[..offsets[n]]`.
+    pub offsets: Vec<usize>,
+}
+
+/// Normalize the string, half-width kana are converted before katakana so
+/// that ｷｶﾚﾏｼﾀ turns into きかれました when both are enabled.
+pub fn normalize(s: &str, normalization: Normalization) -> Normalized {
+    let mut text = String::with_capacity(s.len());
+    let mut offsets = Vec::with_capacity(s.len() + 1);
+    let mut chars = s.char_indices().peekable();
+
+    while let Some((offset, mut c)) = chars.next() {
+        if normalization.contains(Normalization::HALF_WIDTH_KANA) {
+            if let Some(full) = half_width_kana(c) {
+                c = full;
+                let mark = chars.peek().and_then(|&(_, next)| match next {
+                    'ﾞ' => voiced(c),
+                    'ﾟ' => semi_voiced(c),
+                    _ => None,
+                });
+                if let Some(marked) = mark {
+                    c = marked;
+                    chars.next();
+                }
+            }
+        }
+        if normalization.contains(Normalization::KATAKANA) {
+            c = katakana_to_hiragana(c);
+        }
+        if normalization.contains(Normalization::FULL_WIDTH_ASCII) {
+            c = full_width_to_ascii(c);
+        }
+
+        text.push(c);
+        offsets.push(offset);
+    }
+    offsets.push(s.len());
+
+    Normalized { text, offsets }
+}
+
+/// Generate the spelling variants of the string, each together with the
+/// variants that were applied to get it. The string itself is not included.
+///
+/// - [`Variants::LONG_VOWEL_MARK`] removes ー and 〜 following hiragana.
+/// - [`Variants::REPEATED_VOWEL`] shortens runs of the same vowel, including
+///   small vowels, to one and, for runs of three or more, also to two vowels.
+/// - [`Variants::SOKUON`] removes っ in front of kana and replaces a trailing
+///   っ with い, which is how the emphatic stem of i-adjectives is written.
+pub fn variants(s: &str, variants: Variants) -> Vec<(String, Variants)> {
+    let mut out: Vec<(String, Variants)> = Vec::new();
+
+    // try every combination of the enabled variants, fewer variants first
+    let mut combinations = (1..=variants.bits())
+        .map(Variants::from_bits_truncate)
+        .filter(|v| !v.is_empty() && variants.contains(*v))
+        .collect::<Vec<_>>();
+    combinations.sort_by_key(|v| v.bits().count_ones());
+
+    for combination in combinations {
+        let mut candidates = vec![s.to_string()];
+        if combination.contains(Variants::SOKUON) {
+            candidates = candidates.iter().map(|c| without_sokuon(c)).collect();
+        }
+        if combination.contains(Variants::LONG_VOWEL_MARK) {
+            candidates = candidates
+                .iter()
+                .map(|c| without_long_vowel_marks(c))
+                .collect();
+        }
+        if combination.contains(Variants::REPEATED_VOWEL) {
+            candidates = candidates
+                .iter()
+                .flat_map(|c| [shorten_vowels(c, 1), shorten_vowels(c, 2)])
+                .collect();
+        }
+
+        for candidate in candidates {
+            if !candidate.is_empty() && candidate != s && !out.iter().any(|(o, _)| *o == candidate)
+            {
+                out.push((candidate, combination));
+            }
+        }
+    }
+
+    out
+}
+
+fn without_long_vowel_marks(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut prev = None;
+    for c in s.chars() {
+        let after_hiragana = matches!(prev, Some('ぁ'..='ゖ'));
+        if !(matches!(c, 'ー' | '〜' | '～') && after_hiragana) {
+            out.push(c);
+            prev = Some(c);
+        }
+    }
+    out
+}
+
+fn without_sokuon(s: &str) -> String {
+    let trimmed = s.trim_end_matches('っ');
+    let mut out = String::with_capacity(s.len());
+    let mut chars = trimmed.chars().peekable();
+    while let Some(c) = chars.next() {
+        let before_kana = matches!(chars.peek(), Some('ぁ'..='ゖ' | 'ァ'..='ヺ'));
+        if !(c == 'っ' && before_kana && !out.is_empty()) {
+            out.push(c);
+        }
+    }
+    if trimmed.len() != s.len() && !out.is_empty() {
+        out.push('い');
+    }
+    out
+}
+
+/// Shorten runs of the same vowel to at most `max` vowels.
+fn shorten_vowels(s: &str, max: usize) -> String {
+    let mut out = String::with_capacity(s.len());
+    let mut run_vowel = None;
+    let mut run = 0;
+    for c in s.chars() {
+        let vowel = vowel(c);
+        if vowel.is_some() && vowel == run_vowel {
+            run += 1;
+        } else {
+            run_vowel = vowel;
+            run = 1;
+        }
+        if run <= max {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn vowel(c: char) -> Option<char> {
+    match c {
+        'あ' | 'ぁ' => Some('あ'),
+        'い' | 'ぃ' => Some('い'),
+        'う' | 'ぅ' => Some('う'),
+        'え' | 'ぇ' => Some('え'),
+        'お' | 'ぉ' => Some('お'),
+        _ => None,
+    }
+}
+
+fn katakana_to_hiragana(c: char) -> char {
+    match c {
+        'ァ'..='ヶ' | 'ヽ' | 'ヾ' => char::from_u32(c as u32 - 0x60).unwrap_or(c),
+        _ => c,
+    }
+}
+
+fn full_width_to_ascii(c: char) -> char {
+    match c {
+        '！'..='～' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+        '\u{3000}' => ' ',
+        _ => c,
+    }
+}
+
+const HALF_WIDTH_KANA: &str = "｡｢｣､･ｦｧｨｩｪｫｬｭｮｯｰｱｲｳｴｵｶｷｸｹｺｻｼｽｾｿﾀﾁﾂﾃﾄﾅﾆﾇﾈﾉﾊﾋﾌﾍﾎﾏﾐﾑﾒﾓﾔﾕﾖﾗﾘﾙﾚﾛﾜﾝﾞﾟ";
+const FULL_WIDTH_KANA: &str = "。「」、・ヲァィゥェォャュョッーアイウエオカキクケコサシスセソタチツテトナニヌネノハヒフヘホマミムメモヤユヨラリルレロワン゛゜";
+
+fn half_width_kana(c: char) -> Option<char> {
+    if !('｡'..='ﾟ').contains(&c) {
+        return None;
+    }
+    let i = HALF_WIDTH_KANA.chars().position(|h| h == c)?;
+    FULL_WIDTH_KANA.chars().nth(i)
+}
+
+fn voiced(c: char) -> Option<char> {
+    match c {
+        'カ' | 'キ' | 'ク' | 'ケ' | 'コ' | 'サ' | 'シ' | 'ス' | 'セ' | 'ソ' | 'タ' | 'チ'
+        | 'ツ' | 'テ' | 'ト' | 'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => {
+            char::from_u32(c as u32 + 1)
+        }
+        'ウ' => Some('ヴ'),
+        'ワ' => Some('ヷ'),
+        'ヲ' => Some('ヺ'),
+        _ => None,
+    }
+}
+
+fn semi_voiced(c: char) -> Option<char> {
+    match c {
+        'ハ' | 'ヒ' | 'フ' | 'ヘ' | 'ホ' => char::from_u32(c as u32 + 2),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_kana() {
+        let all = Normalization::all();
+        assert_eq!(normalize("キカレマシタ", all).text, "きかれました");
+        assert_eq!(normalize("聞カレマシタ", all).text, "聞かれました");
+        assert_eq!(normalize("ｷｶﾚﾏｼﾀ", all).text, "きかれました");
+        assert_eq!(
+            normalize("ｷｶﾚﾏｼﾀ", Normalization::HALF_WIDTH_KANA).text,
+            "キカレマシタ"
+        );
+        assert_eq!(normalize("ﾀﾞﾒﾊﾟﾝｳﾞ", all).text, "だめぱんゔ");
+        assert_eq!(normalize("ｰﾞ", all).text, "ー゛");
+        assert_eq!(normalize("Ｎｏ．１", all).text, "No.1");
+        assert_eq!(normalize("テスト", Normalization::empty()).text, "テスト");
+    }
+
+    #[test]
+    fn spelling_variants() {
+        let all = Variants::all();
+        let of = |s: &str| variants(s, all);
+        let has = |s: &str, v: &str, applied: Variants| of(s).contains(&(v.to_string(), applied));
+
+        assert!(has("すごーい", "すごい", Variants::LONG_VOWEL_MARK));
+        assert!(has("うるさ〜い", "うるさい", Variants::LONG_VOWEL_MARK));
+        assert!(has("すっごい", "すごい", Variants::SOKUON));
+        assert!(has("寒っ", "寒い", Variants::SOKUON));
+        assert!(has("寒っっ", "寒い", Variants::SOKUON));
+        assert!(has("すごいいい", "すごい", Variants::REPEATED_VOWEL));
+        assert!(has("かわいいいい", "かわいい", Variants::REPEATED_VOWEL));
+        assert!(has("すごいぃ", "すごい", Variants::REPEATED_VOWEL));
+        assert!(has(
+            "すっごーい",
+            "すごい",
+            Variants::SOKUON | Variants::LONG_VOWEL_MARK
+        ));
+
+        assert!(of("コーヒー").is_empty());
+        assert!(of("っ").is_empty());
+        assert!(variants("すごーい", Variants::SOKUON).is_empty());
+        assert!(of("すごい").iter().all(|(v, _)| v != "すごい"));
+    }
+
+    #[test]
+    fn normalize_offsets() {
+        let s = "ﾀﾞﾒだ";
+        let normalized = normalize(s, Normalization::all());
+        assert_eq!(normalized.text, "だめだ");
+        assert_eq!(normalized.offsets, [0, 6, 9, 12]);
+        assert_eq!(&s[..normalized.offsets[1]], "ﾀﾞ");
+        assert_eq!(&s[..normalized.offsets[2]], "ﾀﾞﾒ");
+    }
+}
@@ -1,63 +1,258 @@
-// Generated by mod.rs in deinflect
+// Generated by `cargo xtask codegen` from the rule sets in data/, do not edit.
 
-use super::{r, InflectionRules, Reasons, Rules};
+use super::{r, rp, InflectionRules, Rules};
+use bitflags::bitflags;
+use std::borrow::Cow;
 
-pub const INFLECTION_RULES: &[InflectionRules] = &[
+bitflags! {
+    /// The reasons a word was inflected for, one flag per reason of the rules.
+    ///
+    /// New reasons are only ever appended, so the bits of existing ones are
+    /// stable. Human readable formats serialize the names of the flags, e.g.
+    /// `"NEGATIVE | PAST"`, which doesn't depend on the bits at all.
+    ///
+    /// Binary formats like bincode or postcard serialize the bits, since
+    /// version 0.2 as a `u128` instead of a `u64`, so data written by
+    /// earlier versions can't be read back.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize), serde(transparent))]
+    pub struct Reasons: u128 {
+        const BA = 1;
+        const CHAU = 1 << 1;
+        const CHIMAU = 1 << 2;
+        const SHIMAU = 1 << 3;
+        const NASAI = 1 << 4;
+        const SOU = 1 << 5;
+        const SUGIRU = 1 << 6;
+        const TAI = 1 << 7;
+        const TARA = 1 << 8;
+        const TARI = 1 << 9;
+        const TE = 1 << 10;
+        const ZU = 1 << 11;
+        const NU = 1 << 12;
+        const ADV = 1 << 13;
+        const CAUSATIVE = 1 << 14;
+        const IMPERATIVE = 1 << 15;
+        const IMPERATIVE_NEGATIVE = 1 << 16;
+        const MASU_STEM = 1 << 17;
+        const NEGATIVE = 1 << 18;
+        const NOUN = 1 << 19;
+        const PASSIVE = 1 << 20;
+        const PAST = 1 << 21;
+        const POLITE = 1 << 22;
+        const POLITE_NEGATIVE = 1 << 23;
+        const POLITE_PAST = 1 << 24;
+        const POLITE_PAST_NEGATIVE = 1 << 25;
+        const POLITE_VOLITIONAL = 1 << 26;
+        const POTENTIAL = 1 << 27;
+        const POTENTIAL_OR_PASSIVE = 1 << 28;
+        const VOLITIONAL = 1 << 29;
+        const CAUSATIVE_PASSIVE = 1 << 30;
+        const TOKU = 1 << 31;
+        const PROGRESSIVE_OR_PERFECT = 1 << 32;
+        const KI = 1 << 33;
+        const GE = 1 << 34;
+        const E = 1 << 35;
+        const COPULA = 1 << 36;
+        const ATTRIBUTIVE = 1 << 37;
+        const NAKYA = 1 << 38;
+        const NAKUCHA = 1 << 39;
+        const CHA = 1 << 40;
+        const N = 1 << 41;
+        const ZARU = 1 << 42;
+        const BESHI = 1 << 43;
+        const GOTOSHI = 1 << 44;
+        const PERFECTIVE = 1 << 45;
+        const KERI = 1 << 46;
+        const MU = 1 << 47;
+        const NUREBA = 1 << 48;
+        const SHI = 1 << 49;
+        const KARI = 1 << 50;
+        const KANSAI_NEGATIVE = 1 << 51;
+        const KANSAI_NEGATIVE_PAST = 1 << 52;
+        const KANSAI_HONORIFIC = 1 << 53;
+        const KANSAI_PROGRESSIVE = 1 << 54;
+        const KANSAI_COPULA = 1 << 55;
+        const HONORIFIC = 1 << 56;
+        const HUMBLE = 1 << 57;
+        const TAGARU = 1 << 58;
+        const GARU = 1 << 59;
+        const PPOI = 1 << 60;
+        const RASHII = 1 << 61;
+        const GACHI = 1 << 62;
+        const NAGARA = 1 << 63;
+        const TSUTSU = 1 << 64;
+        const TEMO = 1 << 65;
+        const TEWA = 1 << 66;
+        const TATTE = 1 << 67;
+        const OKU = 1 << 68;
+        const MIRU = 1 << 69;
+        const ARU = 1 << 70;
+        const KURU = 1 << 71;
+        const IKU = 1 << 72;
+        const AGERU = 1 << 73;
+        const KURERU = 1 << 74;
+        const MORAU = 1 << 75;
+        const OBLIGATION = 1 << 76;
+        const NO_OBLIGATION = 1 << 77;
+        const PERMISSION = 1 << 78;
+        const PROHIBITION = 1 << 79;
+        const REQUEST = 1 << 80;
+        const NEGATIVE_REQUEST = 1 << 81;
+        const SHORT_CAUSATIVE = 1 << 82;
+    }
+}
+
+impl Reasons {
+    /// The reasons of [`COLLOQUIAL_RULES`].
+    pub const COLLOQUIAL: Self = Self::from_bits_retain(
+        Self::NAKYA.bits() | Self::NAKUCHA.bits() | Self::CHA.bits() | Self::N.bits(),
+    );
+    /// The reasons of [`CLASSICAL_RULES`].
+    pub const CLASSICAL: Self = Self::from_bits_retain(
+        Self::ZARU.bits()
+            | Self::BESHI.bits()
+            | Self::GOTOSHI.bits()
+            | Self::PERFECTIVE.bits()
+            | Self::KERI.bits()
+            | Self::MU.bits()
+            | Self::NUREBA.bits()
+            | Self::SHI.bits()
+            | Self::KARI.bits(),
+    );
+    /// The reasons of [`KANSAI_RULES`].
+    pub const KANSAI: Self = Self::from_bits_retain(
+        Self::KANSAI_NEGATIVE.bits()
+            | Self::KANSAI_NEGATIVE_PAST.bits()
+            | Self::KANSAI_HONORIFIC.bits()
+            | Self::KANSAI_PROGRESSIVE.bits()
+            | Self::KANSAI_COPULA.bits(),
+    );
+}
+
+/// The rules of standard modern Japanese, used by [`Deinflector::default`](super::Deinflector::default).
+pub static INFLECTION_RULES: &[InflectionRules] = &[
     InflectionRules {
         reason: Reasons::POLITE_PAST_NEGATIVE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("くありませんでした", "い", Rules::empty(), Rules::ADJ_I),
             r("ませんでした", "る", Rules::empty(), Rules::V1),
-            r("いませんでした", "う", Rules::empty(), Rules::V5),
-            r("きませんでした", "く", Rules::empty(), Rules::V5),
+            r(
+                "いませんでした",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きませんでした",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎませんでした", "ぐ", Rules::empty(), Rules::V5),
-            r("しませんでした", "す", Rules::empty(), Rules::V5),
+            r(
+                "しませんでした",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちませんでした", "つ", Rules::empty(), Rules::V5),
             r("にませんでした", "ぬ", Rules::empty(), Rules::V5),
             r("びませんでした", "ぶ", Rules::empty(), Rules::V5),
             r("みませんでした", "む", Rules::empty(), Rules::V5),
-            r("りませんでした", "る", Rules::empty(), Rules::V5),
+            r(
+                "りませんでした",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じませんでした", "ずる", Rules::empty(), Rules::VZ),
             r("しませんでした", "する", Rules::empty(), Rules::VS),
             r("為ませんでした", "為る", Rules::empty(), Rules::VS),
             r("きませんでした", "くる", Rules::empty(), Rules::VK),
             r("来ませんでした", "来る", Rules::empty(), Rules::VK),
             r("來ませんでした", "來る", Rules::empty(), Rules::VK),
-        ],
+            r("じゃありませんでした", "だ", Rules::empty(), Rules::COP),
+            r("ではありませんでした", "だ", Rules::empty(), Rules::COP),
+            r("さいませんでした", "さる", Rules::empty(), Rules::V5ARU),
+            r("しゃいませんでした", "しゃる", Rules::empty(), Rules::V5ARU),
+            r("ざいませんでした", "ざる", Rules::empty(), Rules::V5ARU),
+        ]),
     },
     InflectionRules {
         reason: Reasons::TAI,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("たい", "る", Rules::ADJ_I, Rules::V1),
-            r("いたい", "う", Rules::ADJ_I, Rules::V5),
-            r("きたい", "く", Rules::ADJ_I, Rules::V5),
+            r(
+                "いたい",
+                "う",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きたい",
+                "く",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎたい", "ぐ", Rules::ADJ_I, Rules::V5),
-            r("したい", "す", Rules::ADJ_I, Rules::V5),
+            r(
+                "したい",
+                "す",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちたい", "つ", Rules::ADJ_I, Rules::V5),
             r("にたい", "ぬ", Rules::ADJ_I, Rules::V5),
             r("びたい", "ぶ", Rules::ADJ_I, Rules::V5),
             r("みたい", "む", Rules::ADJ_I, Rules::V5),
-            r("りたい", "る", Rules::ADJ_I, Rules::V5),
+            r(
+                "りたい",
+                "る",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じたい", "ずる", Rules::ADJ_I, Rules::VZ),
             r("したい", "する", Rules::ADJ_I, Rules::VS),
             r("為たい", "為る", Rules::ADJ_I, Rules::VS),
             r("きたい", "くる", Rules::ADJ_I, Rules::VK),
             r("来たい", "来る", Rules::ADJ_I, Rules::VK),
             r("來たい", "來る", Rules::ADJ_I, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::PASSIVE,
-        rules: &[
-            r("かれる", "く", Rules::V1, Rules::V5),
+        rules: Cow::Borrowed(&[
+            r(
+                "かれる",
+                "く",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("がれる", "ぐ", Rules::V1, Rules::V5),
             r("される", "す", Rules::V1, Rules::V5),
             r("たれる", "つ", Rules::V1, Rules::V5),
             r("なれる", "ぬ", Rules::V1, Rules::V5),
             r("ばれる", "ぶ", Rules::V1, Rules::V5),
             r("まれる", "む", Rules::V1, Rules::V5),
-            r("われる", "う", Rules::V1, Rules::V5),
-            r("られる", "る", Rules::V1, Rules::V5),
+            r(
+                "われる",
+                "う",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "られる",
+                "る",
+                Rules::V1,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じされる", "ずる", Rules::V1, Rules::VZ),
             r("ぜされる", "ずる", Rules::V1, Rules::VZ),
             r("される", "する", Rules::V1, Rules::VS),
@@ -65,18 +260,30 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("こられる", "くる", Rules::V1, Rules::VK),
             r("来られる", "来る", Rules::V1, Rules::VK),
             r("來られる", "來る", Rules::V1, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::TOKU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("とく", "る", Rules::V5, Rules::V1),
             r("いとく", "く", Rules::V5, Rules::V5),
             r("いどく", "ぐ", Rules::V5, Rules::V5),
-            r("しとく", "す", Rules::V5, Rules::V5),
+            r(
+                "しとく",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("っとく", "う", Rules::V5, Rules::V5),
             r("っとく", "つ", Rules::V5, Rules::V5),
-            r("っとく", "る", Rules::V5, Rules::V5),
+            r(
+                "っとく",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("んどく", "ぬ", Rules::V5, Rules::V5),
             r("んどく", "ぶ", Rules::V5, Rules::V5),
             r("んどく", "む", Rules::V5, Rules::V5),
@@ -86,84 +293,169 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("きとく", "くる", Rules::V5, Rules::VK),
             r("来とく", "来る", Rules::V5, Rules::VK),
             r("來とく", "來る", Rules::V5, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::GE,
-        rules: &[r("しげ", "しい", Rules::empty(), Rules::ADJ_I)],
+        rules: Cow::Borrowed(&[r("しげ", "しい", Rules::empty(), Rules::ADJ_I)]),
     },
     InflectionRules {
         reason: Reasons::NASAI,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("なさい", "る", Rules::empty(), Rules::V1),
-            r("いなさい", "う", Rules::empty(), Rules::V5),
-            r("きなさい", "く", Rules::empty(), Rules::V5),
+            r(
+                "いなさい",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きなさい",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎなさい", "ぐ", Rules::empty(), Rules::V5),
-            r("しなさい", "す", Rules::empty(), Rules::V5),
+            r(
+                "しなさい",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちなさい", "つ", Rules::empty(), Rules::V5),
             r("になさい", "ぬ", Rules::empty(), Rules::V5),
             r("びなさい", "ぶ", Rules::empty(), Rules::V5),
             r("みなさい", "む", Rules::empty(), Rules::V5),
-            r("りなさい", "る", Rules::empty(), Rules::V5),
+            r(
+                "りなさい",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じなさい", "ずる", Rules::empty(), Rules::VZ),
             r("しなさい", "する", Rules::empty(), Rules::VS),
             r("為なさい", "為る", Rules::empty(), Rules::VS),
             r("きなさい", "くる", Rules::empty(), Rules::VK),
             r("来なさい", "来る", Rules::empty(), Rules::VK),
             r("來なさい", "來る", Rules::empty(), Rules::VK),
-        ],
+            r("なさいませ", "る", Rules::empty(), Rules::V1),
+            r(
+                "いなさいませ",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きなさいませ",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎなさいませ", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "しなさいませ",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちなさいませ", "つ", Rules::empty(), Rules::V5),
+            r("になさいませ", "ぬ", Rules::empty(), Rules::V5),
+            r("びなさいませ", "ぶ", Rules::empty(), Rules::V5),
+            r("みなさいませ", "む", Rules::empty(), Rules::V5),
+            r(
+                "りなさいませ",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("じなさいませ", "ずる", Rules::empty(), Rules::VZ),
+            r("しなさいませ", "する", Rules::empty(), Rules::VS),
+            r("為なさいませ", "為る", Rules::empty(), Rules::VS),
+            r("きなさいませ", "くる", Rules::empty(), Rules::VK),
+            r("来なさいませ", "来る", Rules::empty(), Rules::VK),
+            r("來なさいませ", "來る", Rules::empty(), Rules::VK),
+        ]),
     },
     InflectionRules {
         reason: Reasons::PAST,
-        rules: &[
-            r("かった", "い", Rules::empty(), Rules::ADJ_I),
-            r("た", "る", Rules::empty(), Rules::V1),
-            r("いた", "く", Rules::empty(), Rules::V5),
-            r("いだ", "ぐ", Rules::empty(), Rules::V5),
-            r("した", "す", Rules::empty(), Rules::V5),
-            r("った", "う", Rules::empty(), Rules::V5),
-            r("った", "つ", Rules::empty(), Rules::V5),
-            r("った", "る", Rules::empty(), Rules::V5),
-            r("んだ", "ぬ", Rules::empty(), Rules::V5),
-            r("んだ", "ぶ", Rules::empty(), Rules::V5),
-            r("んだ", "む", Rules::empty(), Rules::V5),
-            r("じた", "ずる", Rules::empty(), Rules::VZ),
-            r("した", "する", Rules::empty(), Rules::VS),
-            r("為た", "為る", Rules::empty(), Rules::VS),
-            r("きた", "くる", Rules::empty(), Rules::VK),
-            r("来た", "来る", Rules::empty(), Rules::VK),
-            r("來た", "來る", Rules::empty(), Rules::VK),
-            r("いった", "いく", Rules::empty(), Rules::V5),
-            r("おうた", "おう", Rules::empty(), Rules::V5),
-            r("こうた", "こう", Rules::empty(), Rules::V5),
-            r("そうた", "そう", Rules::empty(), Rules::V5),
-            r("とうた", "とう", Rules::empty(), Rules::V5),
-            r("行った", "行く", Rules::empty(), Rules::V5),
-            r("逝った", "逝く", Rules::empty(), Rules::V5),
-            r("往った", "往く", Rules::empty(), Rules::V5),
-            r("請うた", "請う", Rules::empty(), Rules::V5),
-            r("乞うた", "乞う", Rules::empty(), Rules::V5),
-            r("恋うた", "恋う", Rules::empty(), Rules::V5),
-            r("問うた", "問う", Rules::empty(), Rules::V5),
-            r("負うた", "負う", Rules::empty(), Rules::V5),
-            r("沿うた", "沿う", Rules::empty(), Rules::V5),
-            r("添うた", "添う", Rules::empty(), Rules::V5),
-            r("副うた", "副う", Rules::empty(), Rules::V5),
-            r("厭うた", "厭う", Rules::empty(), Rules::V5),
-            r("のたもうた", "のたまう", Rules::empty(), Rules::V5),
-        ],
+        rules: Cow::Borrowed(&[
+            r("かった", "い", Rules::PAST, Rules::ADJ_I),
+            r("た", "る", Rules::PAST, Rules::V1),
+            r("いた", "く", Rules::PAST, Rules::V5),
+            r("いだ", "ぐ", Rules::PAST, Rules::V5),
+            r(
+                "した",
+                "す",
+                Rules::PAST,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("った", "う", Rules::PAST, Rules::V5),
+            r("った", "つ", Rules::PAST, Rules::V5),
+            r(
+                "った",
+                "る",
+                Rules::PAST,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("んだ", "ぬ", Rules::PAST, Rules::V5),
+            r("んだ", "ぶ", Rules::PAST, Rules::V5),
+            r("んだ", "む", Rules::PAST, Rules::V5),
+            r("じた", "ずる", Rules::PAST, Rules::VZ),
+            r("した", "する", Rules::PAST, Rules::VS),
+            r("為た", "為る", Rules::PAST, Rules::VS),
+            r("きた", "くる", Rules::PAST, Rules::VK),
+            r("来た", "来る", Rules::PAST, Rules::VK),
+            r("來た", "來る", Rules::PAST, Rules::VK),
+            r("いった", "いく", Rules::PAST, Rules::V5K_S),
+            r("おうた", "おう", Rules::PAST, Rules::V5),
+            r("こうた", "こう", Rules::PAST, Rules::V5U_S),
+            r("そうた", "そう", Rules::PAST, Rules::V5),
+            r("とうた", "とう", Rules::PAST, Rules::V5U_S),
+            r("行った", "行く", Rules::PAST, Rules::V5K_S),
+            r("逝った", "逝く", Rules::PAST, Rules::V5K_S),
+            r("往った", "往く", Rules::PAST, Rules::V5K_S),
+            r("請うた", "請う", Rules::PAST, Rules::V5U_S),
+            r("乞うた", "乞う", Rules::PAST, Rules::V5U_S),
+            r("恋うた", "恋う", Rules::PAST, Rules::V5U_S),
+            r("問うた", "問う", Rules::PAST, Rules::V5U_S),
+            r("負うた", "負う", Rules::PAST, Rules::V5),
+            r("沿うた", "沿う", Rules::PAST, Rules::V5),
+            r("添うた", "添う", Rules::PAST, Rules::V5),
+            r("副うた", "副う", Rules::PAST, Rules::V5),
+            r("厭うた", "厭う", Rules::PAST, Rules::V5),
+            r("のたもうた", "のたまう", Rules::PAST, Rules::V5),
+            r("だった", "だ", Rules::PAST, Rules::COP),
+        ]),
     },
     InflectionRules {
         reason: Reasons::TARI,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("かったり", "い", Rules::empty(), Rules::ADJ_I),
             r("たり", "る", Rules::empty(), Rules::V1),
             r("いたり", "く", Rules::empty(), Rules::V5),
             r("いだり", "ぐ", Rules::empty(), Rules::V5),
-            r("したり", "す", Rules::empty(), Rules::V5),
+            r(
+                "したり",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ったり", "う", Rules::empty(), Rules::V5),
             r("ったり", "つ", Rules::empty(), Rules::V5),
-            r("ったり", "る", Rules::empty(), Rules::V5),
+            r(
+                "ったり",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("んだり", "ぬ", Rules::empty(), Rules::V5),
             r("んだり", "ぶ", Rules::empty(), Rules::V5),
             r("んだり", "む", Rules::empty(), Rules::V5),
@@ -173,58 +465,94 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("きたり", "くる", Rules::empty(), Rules::VK),
             r("来たり", "来る", Rules::empty(), Rules::VK),
             r("來たり", "來る", Rules::empty(), Rules::VK),
-            r("いったり", "いく", Rules::empty(), Rules::V5),
+            r("いったり", "いく", Rules::empty(), Rules::V5K_S),
             r("おうたり", "おう", Rules::empty(), Rules::V5),
-            r("こうたり", "こう", Rules::empty(), Rules::V5),
+            r("こうたり", "こう", Rules::empty(), Rules::V5U_S),
             r("そうたり", "そう", Rules::empty(), Rules::V5),
-            r("とうたり", "とう", Rules::empty(), Rules::V5),
-            r("行ったり", "行く", Rules::empty(), Rules::V5),
-            r("逝ったり", "逝く", Rules::empty(), Rules::V5),
-            r("往ったり", "往く", Rules::empty(), Rules::V5),
-            r("請うたり", "請う", Rules::empty(), Rules::V5),
-            r("乞うたり", "乞う", Rules::empty(), Rules::V5),
-            r("恋うたり", "恋う", Rules::empty(), Rules::V5),
-            r("問うたり", "問う", Rules::empty(), Rules::V5),
+            r("とうたり", "とう", Rules::empty(), Rules::V5U_S),
+            r("行ったり", "行く", Rules::empty(), Rules::V5K_S),
+            r("逝ったり", "逝く", Rules::empty(), Rules::V5K_S),
+            r("往ったり", "往く", Rules::empty(), Rules::V5K_S),
+            r("請うたり", "請う", Rules::empty(), Rules::V5U_S),
+            r("乞うたり", "乞う", Rules::empty(), Rules::V5U_S),
+            r("恋うたり", "恋う", Rules::empty(), Rules::V5U_S),
+            r("問うたり", "問う", Rules::empty(), Rules::V5U_S),
             r("負うたり", "負う", Rules::empty(), Rules::V5),
             r("沿うたり", "沿う", Rules::empty(), Rules::V5),
             r("添うたり", "添う", Rules::empty(), Rules::V5),
             r("副うたり", "副う", Rules::empty(), Rules::V5),
             r("厭うたり", "厭う", Rules::empty(), Rules::V5),
             r("のたもうたり", "のたまう", Rules::empty(), Rules::V5),
-        ],
+            r("だったり", "だ", Rules::empty(), Rules::COP),
+            r("でしたり", "だ", Rules::empty(), Rules::COP),
+        ]),
     },
     InflectionRules {
         reason: Reasons::VOLITIONAL,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("よう", "る", Rules::empty(), Rules::V1),
-            r("おう", "う", Rules::empty(), Rules::V5),
-            r("こう", "く", Rules::empty(), Rules::V5),
+            r(
+                "おう",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "こう",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ごう", "ぐ", Rules::empty(), Rules::V5),
-            r("そう", "す", Rules::empty(), Rules::V5),
+            r(
+                "そう",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("とう", "つ", Rules::empty(), Rules::V5),
             r("のう", "ぬ", Rules::empty(), Rules::V5),
             r("ぼう", "ぶ", Rules::empty(), Rules::V5),
             r("もう", "む", Rules::empty(), Rules::V5),
-            r("ろう", "る", Rules::empty(), Rules::V5),
+            r(
+                "ろう",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じよう", "ずる", Rules::empty(), Rules::VZ),
             r("しよう", "する", Rules::empty(), Rules::VS),
             r("為よう", "為る", Rules::empty(), Rules::VS),
             r("こよう", "くる", Rules::empty(), Rules::VK),
             r("来よう", "来る", Rules::empty(), Rules::VK),
             r("來よう", "來る", Rules::empty(), Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::CHAU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ちゃう", "る", Rules::V5, Rules::V1),
             r("いじゃう", "ぐ", Rules::V5, Rules::V5),
             r("いちゃう", "く", Rules::V5, Rules::V5),
-            r("しちゃう", "す", Rules::V5, Rules::V5),
+            r(
+                "しちゃう",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("っちゃう", "う", Rules::V5, Rules::V5),
-            r("っちゃう", "く", Rules::V5, Rules::V5),
+            r("っちゃう", "く", Rules::V5, Rules::V5K_S),
             r("っちゃう", "つ", Rules::V5, Rules::V5),
-            r("っちゃう", "る", Rules::V5, Rules::V5),
+            r(
+                "っちゃう",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("んじゃう", "ぬ", Rules::V5, Rules::V5),
             r("んじゃう", "ぶ", Rules::V5, Rules::V5),
             r("んじゃう", "む", Rules::V5, Rules::V5),
@@ -234,19 +562,31 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("きちゃう", "くる", Rules::V5, Rules::VK),
             r("来ちゃう", "来る", Rules::V5, Rules::VK),
             r("來ちゃう", "來る", Rules::V5, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::TE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("くて", "い", Rules::IRU, Rules::ADJ_I),
             r("て", "る", Rules::IRU, Rules::V1),
             r("いて", "く", Rules::IRU, Rules::V5),
             r("いで", "ぐ", Rules::IRU, Rules::V5),
-            r("して", "す", Rules::IRU, Rules::V5),
+            r(
+                "して",
+                "す",
+                Rules::IRU,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("って", "う", Rules::IRU, Rules::V5),
             r("って", "つ", Rules::IRU, Rules::V5),
-            r("って", "る", Rules::IRU, Rules::V5),
+            r(
+                "って",
+                "る",
+                Rules::IRU,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("んで", "ぬ", Rules::IRU, Rules::V5),
             r("んで", "ぶ", Rules::IRU, Rules::V5),
             r("んで", "む", Rules::IRU, Rules::V5),
@@ -256,95 +596,166 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("きて", "くる", Rules::IRU, Rules::VK),
             r("来て", "来る", Rules::IRU, Rules::VK),
             r("來て", "來る", Rules::IRU, Rules::VK),
-            r("いって", "いく", Rules::IRU, Rules::V5),
+            r("いって", "いく", Rules::IRU, Rules::V5K_S),
             r("おうて", "おう", Rules::IRU, Rules::V5),
-            r("こうて", "こう", Rules::IRU, Rules::V5),
+            r("こうて", "こう", Rules::IRU, Rules::V5U_S),
             r("そうて", "そう", Rules::IRU, Rules::V5),
-            r("とうて", "とう", Rules::IRU, Rules::V5),
-            r("行って", "行く", Rules::IRU, Rules::V5),
-            r("逝って", "逝く", Rules::IRU, Rules::V5),
-            r("往って", "往く", Rules::IRU, Rules::V5),
-            r("請うて", "請う", Rules::IRU, Rules::V5),
-            r("乞うて", "乞う", Rules::IRU, Rules::V5),
-            r("恋うて", "恋う", Rules::IRU, Rules::V5),
-            r("問うて", "問う", Rules::IRU, Rules::V5),
+            r("とうて", "とう", Rules::IRU, Rules::V5U_S),
+            r("行って", "行く", Rules::IRU, Rules::V5K_S),
+            r("逝って", "逝く", Rules::IRU, Rules::V5K_S),
+            r("往って", "往く", Rules::IRU, Rules::V5K_S),
+            r("請うて", "請う", Rules::IRU, Rules::V5U_S),
+            r("乞うて", "乞う", Rules::IRU, Rules::V5U_S),
+            r("恋うて", "恋う", Rules::IRU, Rules::V5U_S),
+            r("問うて", "問う", Rules::IRU, Rules::V5U_S),
             r("負うて", "負う", Rules::IRU, Rules::V5),
             r("沿うて", "沿う", Rules::IRU, Rules::V5),
             r("添うて", "添う", Rules::IRU, Rules::V5),
             r("副うて", "副う", Rules::IRU, Rules::V5),
             r("厭うて", "厭う", Rules::IRU, Rules::V5),
             r("のたもうて", "のたまう", Rules::IRU, Rules::V5),
-        ],
+            r("で", "だ", Rules::empty(), Rules::COP),
+        ]),
     },
     InflectionRules {
         reason: Reasons::MASU_STEM,
-        rules: &[
-            r("い", "いる", Rules::empty(), Rules::V1),
-            r("え", "える", Rules::empty(), Rules::V1),
-            r("き", "きる", Rules::empty(), Rules::V1),
-            r("ぎ", "ぎる", Rules::empty(), Rules::V1),
-            r("け", "ける", Rules::empty(), Rules::V1),
-            r("げ", "げる", Rules::empty(), Rules::V1),
-            r("じ", "じる", Rules::empty(), Rules::V1),
-            r("せ", "せる", Rules::empty(), Rules::V1),
-            r("ぜ", "ぜる", Rules::empty(), Rules::V1),
-            r("ち", "ちる", Rules::empty(), Rules::V1),
-            r("て", "てる", Rules::empty(), Rules::V1),
-            r("で", "でる", Rules::empty(), Rules::V1),
-            r("に", "にる", Rules::empty(), Rules::V1),
-            r("ね", "ねる", Rules::empty(), Rules::V1),
-            r("ひ", "ひる", Rules::empty(), Rules::V1),
-            r("び", "びる", Rules::empty(), Rules::V1),
-            r("へ", "へる", Rules::empty(), Rules::V1),
-            r("べ", "べる", Rules::empty(), Rules::V1),
-            r("み", "みる", Rules::empty(), Rules::V1),
-            r("め", "める", Rules::empty(), Rules::V1),
-            r("り", "りる", Rules::empty(), Rules::V1),
-            r("れ", "れる", Rules::empty(), Rules::V1),
-            r("い", "う", Rules::empty(), Rules::V5),
-            r("き", "く", Rules::empty(), Rules::V5),
-            r("ぎ", "ぐ", Rules::empty(), Rules::V5),
-            r("し", "す", Rules::empty(), Rules::V5),
-            r("ち", "つ", Rules::empty(), Rules::V5),
-            r("に", "ぬ", Rules::empty(), Rules::V5),
-            r("び", "ぶ", Rules::empty(), Rules::V5),
-            r("み", "む", Rules::empty(), Rules::V5),
-            r("り", "る", Rules::empty(), Rules::V5),
-            r("き", "くる", Rules::empty(), Rules::VK),
-            r("来", "来る", Rules::empty(), Rules::VK),
-            r("來", "來る", Rules::empty(), Rules::VK),
-        ],
+        rules: Cow::Borrowed(&[
+            r("い", "いる", Rules::MASU_STEM, Rules::V1),
+            r("え", "える", Rules::MASU_STEM, Rules::V1),
+            r("き", "きる", Rules::MASU_STEM, Rules::V1),
+            r("ぎ", "ぎる", Rules::MASU_STEM, Rules::V1),
+            r("け", "ける", Rules::MASU_STEM, Rules::V1),
+            r("げ", "げる", Rules::MASU_STEM, Rules::V1),
+            r("じ", "じる", Rules::MASU_STEM, Rules::V1),
+            r("せ", "せる", Rules::MASU_STEM, Rules::V1),
+            r("ぜ", "ぜる", Rules::MASU_STEM, Rules::V1),
+            r("ち", "ちる", Rules::MASU_STEM, Rules::V1),
+            r("て", "てる", Rules::MASU_STEM, Rules::V1),
+            r("で", "でる", Rules::MASU_STEM, Rules::V1),
+            r("に", "にる", Rules::MASU_STEM, Rules::V1),
+            r("ね", "ねる", Rules::MASU_STEM, Rules::V1),
+            r("ひ", "ひる", Rules::MASU_STEM, Rules::V1),
+            r("び", "びる", Rules::MASU_STEM, Rules::V1),
+            r("へ", "へる", Rules::MASU_STEM, Rules::V1),
+            r("べ", "べる", Rules::MASU_STEM, Rules::V1),
+            r("み", "みる", Rules::MASU_STEM, Rules::V1),
+            r("め", "める", Rules::MASU_STEM, Rules::V1),
+            r("り", "りる", Rules::MASU_STEM, Rules::V1),
+            r("れ", "れる", Rules::MASU_STEM, Rules::V1),
+            r(
+                "い",
+                "う",
+                Rules::MASU_STEM,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "き",
+                "く",
+                Rules::MASU_STEM,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎ", "ぐ", Rules::MASU_STEM, Rules::V5),
+            r(
+                "し",
+                "す",
+                Rules::MASU_STEM,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ち", "つ", Rules::MASU_STEM, Rules::V5),
+            r("に", "ぬ", Rules::MASU_STEM, Rules::V5),
+            r("び", "ぶ", Rules::MASU_STEM, Rules::V5),
+            r("み", "む", Rules::MASU_STEM, Rules::V5),
+            r(
+                "り",
+                "る",
+                Rules::MASU_STEM,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("き", "くる", Rules::MASU_STEM, Rules::VK),
+            r("来", "来る", Rules::MASU_STEM, Rules::VK),
+            r("來", "來る", Rules::MASU_STEM, Rules::VK),
+            r("さい", "さる", Rules::MASU_STEM, Rules::V5ARU),
+            r("しゃい", "しゃる", Rules::MASU_STEM, Rules::V5ARU),
+            r("ざい", "ざる", Rules::MASU_STEM, Rules::V5ARU),
+            r("じ", "ずる", Rules::MASU_STEM, Rules::VZ),
+            r("し", "する", Rules::MASU_STEM, Rules::VS),
+            r("為", "為る", Rules::MASU_STEM, Rules::VS),
+        ]),
     },
     InflectionRules {
         reason: Reasons::POLITE_NEGATIVE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("くありません", "い", Rules::empty(), Rules::ADJ_I),
             r("ません", "る", Rules::empty(), Rules::V1),
-            r("いません", "う", Rules::empty(), Rules::V5),
-            r("きません", "く", Rules::empty(), Rules::V5),
+            r(
+                "いません",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きません",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎません", "ぐ", Rules::empty(), Rules::V5),
-            r("しません", "す", Rules::empty(), Rules::V5),
+            r(
+                "しません",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちません", "つ", Rules::empty(), Rules::V5),
             r("にません", "ぬ", Rules::empty(), Rules::V5),
             r("びません", "ぶ", Rules::empty(), Rules::V5),
             r("みません", "む", Rules::empty(), Rules::V5),
-            r("りません", "る", Rules::empty(), Rules::V5),
+            r(
+                "りません",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じません", "ずる", Rules::empty(), Rules::VZ),
             r("しません", "する", Rules::empty(), Rules::VS),
             r("為ません", "為る", Rules::empty(), Rules::VS),
             r("きません", "くる", Rules::empty(), Rules::VK),
             r("来ません", "来る", Rules::empty(), Rules::VK),
             r("來ません", "來る", Rules::empty(), Rules::VK),
-        ],
+            r("じゃありません", "だ", Rules::empty(), Rules::COP),
+            r("ではありません", "だ", Rules::empty(), Rules::COP),
+            r("さいません", "さる", Rules::empty(), Rules::V5ARU),
+            r("しゃいません", "しゃる", Rules::empty(), Rules::V5ARU),
+            r("ざいません", "ざる", Rules::empty(), Rules::V5ARU),
+        ]),
     },
     InflectionRules {
         reason: Reasons::BA,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ければ", "い", Rules::empty(), Rules::ADJ_I),
-            r("えば", "う", Rules::empty(), Rules::V5),
-            r("けば", "く", Rules::empty(), Rules::V5),
+            r(
+                "えば",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "けば",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("げば", "ぐ", Rules::empty(), Rules::V5),
-            r("せば", "す", Rules::empty(), Rules::V5),
+            r(
+                "せば",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("てば", "つ", Rules::empty(), Rules::V5),
             r("ねば", "ぬ", Rules::empty(), Rules::V5),
             r("べば", "ぶ", Rules::empty(), Rules::V5),
@@ -361,19 +772,31 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
                         | Rules::VZ.bits(),
                 ),
             ),
-        ],
+            r("なら", "だ", Rules::empty(), Rules::COP),
+            r("であれば", "だ", Rules::empty(), Rules::COP),
+        ]),
     },
     InflectionRules {
         reason: Reasons::POTENTIAL,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r(
                 "れる",
                 "る",
                 Rules::V1,
                 Rules::from_bits_retain(Rules::V1.bits() | Rules::V5.bits()),
             ),
-            r("える", "う", Rules::V1, Rules::V5),
-            r("ける", "く", Rules::V1, Rules::V5),
+            r(
+                "える",
+                "う",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "ける",
+                "く",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("げる", "ぐ", Rules::V1, Rules::V5),
             r("せる", "す", Rules::V1, Rules::V5),
             r("てる", "つ", Rules::V1, Rules::V5),
@@ -383,11 +806,11 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("これる", "くる", Rules::V1, Rules::VK),
             r("来れる", "来る", Rules::V1, Rules::VK),
             r("來れる", "來る", Rules::V1, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::PROGRESSIVE_OR_PERFECT,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ている", "て", Rules::V1, Rules::IRU),
             r("ておる", "て", Rules::V5, Rules::IRU),
             r("てる", "て", Rules::V1, Rules::IRU),
@@ -396,34 +819,47 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("でる", "で", Rules::V1, Rules::IRU),
             r("とる", "て", Rules::V5, Rules::IRU),
             r("ないでいる", "ない", Rules::V1, Rules::ADJ_I),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::IMPERATIVE_NEGATIVE,
-        rules: &[r(
+        rules: Cow::Borrowed(&[r(
             "な",
             "",
             Rules::empty(),
             Rules::from_bits_retain(
                 Rules::V1.bits()
                     | Rules::V5.bits()
+                    | Rules::V5S.bits()
                     | Rules::VK.bits()
                     | Rules::VS.bits()
                     | Rules::VZ.bits(),
             ),
-        )],
+        )]),
     },
     InflectionRules {
         reason: Reasons::CHIMAU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ちまう", "る", Rules::V5, Rules::V1),
             r("いじまう", "ぐ", Rules::V5, Rules::V5),
             r("いちまう", "く", Rules::V5, Rules::V5),
-            r("しちまう", "す", Rules::V5, Rules::V5),
+            r(
+                "しちまう",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("っちまう", "う", Rules::V5, Rules::V5),
-            r("っちまう", "く", Rules::V5, Rules::V5),
+            r("っちまう", "く", Rules::V5, Rules::V5K_S),
             r("っちまう", "つ", Rules::V5, Rules::V5),
-            r("っちまう", "る", Rules::V5, Rules::V5),
+            r(
+                "っちまう",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("んじまう", "ぬ", Rules::V5, Rules::V5),
             r("んじまう", "ぶ", Rules::V5, Rules::V5),
             r("んじまう", "む", Rules::V5, Rules::V5),
@@ -433,63 +869,120 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("きちまう", "くる", Rules::V5, Rules::VK),
             r("来ちまう", "来る", Rules::V5, Rules::VK),
             r("來ちまう", "來る", Rules::V5, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::SUGIRU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("すぎる", "い", Rules::V1, Rules::ADJ_I),
             r("すぎる", "る", Rules::V1, Rules::V1),
-            r("いすぎる", "う", Rules::V1, Rules::V5),
-            r("きすぎる", "く", Rules::V1, Rules::V5),
+            r(
+                "いすぎる",
+                "う",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きすぎる",
+                "く",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎすぎる", "ぐ", Rules::V1, Rules::V5),
-            r("しすぎる", "す", Rules::V1, Rules::V5),
+            r(
+                "しすぎる",
+                "す",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちすぎる", "つ", Rules::V1, Rules::V5),
             r("にすぎる", "ぬ", Rules::V1, Rules::V5),
             r("びすぎる", "ぶ", Rules::V1, Rules::V5),
             r("みすぎる", "む", Rules::V1, Rules::V5),
-            r("りすぎる", "る", Rules::V1, Rules::V5),
+            r(
+                "りすぎる",
+                "る",
+                Rules::V1,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じすぎる", "ずる", Rules::V1, Rules::VZ),
             r("しすぎる", "する", Rules::V1, Rules::VS),
             r("為すぎる", "為る", Rules::V1, Rules::VS),
             r("きすぎる", "くる", Rules::V1, Rules::VK),
             r("来すぎる", "来る", Rules::V1, Rules::VK),
             r("來すぎる", "來る", Rules::V1, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::NEGATIVE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("くない", "い", Rules::ADJ_I, Rules::ADJ_I),
             r("ない", "る", Rules::ADJ_I, Rules::V1),
-            r("かない", "く", Rules::ADJ_I, Rules::V5),
+            r(
+                "かない",
+                "く",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("がない", "ぐ", Rules::ADJ_I, Rules::V5),
-            r("さない", "す", Rules::ADJ_I, Rules::V5),
+            r(
+                "さない",
+                "す",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("たない", "つ", Rules::ADJ_I, Rules::V5),
             r("なない", "ぬ", Rules::ADJ_I, Rules::V5),
             r("ばない", "ぶ", Rules::ADJ_I, Rules::V5),
             r("まない", "む", Rules::ADJ_I, Rules::V5),
-            r("らない", "る", Rules::ADJ_I, Rules::V5),
-            r("わない", "う", Rules::ADJ_I, Rules::V5),
+            r(
+                "らない",
+                "る",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5ARU.bits()),
+            ),
+            r(
+                "わない",
+                "う",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
             r("じない", "ずる", Rules::ADJ_I, Rules::VZ),
             r("しない", "する", Rules::ADJ_I, Rules::VS),
             r("為ない", "為る", Rules::ADJ_I, Rules::VS),
             r("こない", "くる", Rules::ADJ_I, Rules::VK),
             r("来ない", "来る", Rules::ADJ_I, Rules::VK),
             r("來ない", "來る", Rules::ADJ_I, Rules::VK),
-        ],
+            r("じゃない", "だ", Rules::ADJ_I, Rules::COP),
+            r("ではない", "だ", Rules::ADJ_I, Rules::COP),
+            r("ない", "ある", Rules::ADJ_I, Rules::V5R_I),
+        ]),
     },
     InflectionRules {
         reason: Reasons::TARA,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("かったら", "い", Rules::empty(), Rules::ADJ_I),
             r("たら", "る", Rules::empty(), Rules::V1),
             r("いたら", "く", Rules::empty(), Rules::V5),
             r("いだら", "ぐ", Rules::empty(), Rules::V5),
-            r("したら", "す", Rules::empty(), Rules::V5),
+            r(
+                "したら",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ったら", "う", Rules::empty(), Rules::V5),
             r("ったら", "つ", Rules::empty(), Rules::V5),
-            r("ったら", "る", Rules::empty(), Rules::V5),
+            r(
+                "ったら",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("んだら", "ぬ", Rules::empty(), Rules::V5),
             r("んだら", "ぶ", Rules::empty(), Rules::V5),
             r("んだら", "む", Rules::empty(), Rules::V5),
@@ -499,81 +992,152 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("きたら", "くる", Rules::empty(), Rules::VK),
             r("来たら", "来る", Rules::empty(), Rules::VK),
             r("來たら", "來る", Rules::empty(), Rules::VK),
-            r("いったら", "いく", Rules::empty(), Rules::V5),
+            r("いったら", "いく", Rules::empty(), Rules::V5K_S),
             r("おうたら", "おう", Rules::empty(), Rules::V5),
-            r("こうたら", "こう", Rules::empty(), Rules::V5),
+            r("こうたら", "こう", Rules::empty(), Rules::V5U_S),
             r("そうたら", "そう", Rules::empty(), Rules::V5),
-            r("とうたら", "とう", Rules::empty(), Rules::V5),
-            r("行ったら", "行く", Rules::empty(), Rules::V5),
-            r("逝ったら", "逝く", Rules::empty(), Rules::V5),
-            r("往ったら", "往く", Rules::empty(), Rules::V5),
-            r("請うたら", "請う", Rules::empty(), Rules::V5),
-            r("乞うたら", "乞う", Rules::empty(), Rules::V5),
-            r("恋うたら", "恋う", Rules::empty(), Rules::V5),
-            r("問うたら", "問う", Rules::empty(), Rules::V5),
+            r("とうたら", "とう", Rules::empty(), Rules::V5U_S),
+            r("行ったら", "行く", Rules::empty(), Rules::V5K_S),
+            r("逝ったら", "逝く", Rules::empty(), Rules::V5K_S),
+            r("往ったら", "往く", Rules::empty(), Rules::V5K_S),
+            r("請うたら", "請う", Rules::empty(), Rules::V5U_S),
+            r("乞うたら", "乞う", Rules::empty(), Rules::V5U_S),
+            r("恋うたら", "恋う", Rules::empty(), Rules::V5U_S),
+            r("問うたら", "問う", Rules::empty(), Rules::V5U_S),
             r("負うたら", "負う", Rules::empty(), Rules::V5),
             r("沿うたら", "沿う", Rules::empty(), Rules::V5),
             r("添うたら", "添う", Rules::empty(), Rules::V5),
             r("副うたら", "副う", Rules::empty(), Rules::V5),
             r("厭うたら", "厭う", Rules::empty(), Rules::V5),
             r("のたもうたら", "のたまう", Rules::empty(), Rules::V5),
-        ],
+            r("だったら", "だ", Rules::empty(), Rules::COP),
+            r("でしたら", "だ", Rules::empty(), Rules::COP),
+        ]),
     },
     InflectionRules {
         reason: Reasons::POLITE_VOLITIONAL,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ましょう", "る", Rules::empty(), Rules::V1),
-            r("いましょう", "う", Rules::empty(), Rules::V5),
-            r("きましょう", "く", Rules::empty(), Rules::V5),
+            r(
+                "いましょう",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きましょう",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎましょう", "ぐ", Rules::empty(), Rules::V5),
-            r("しましょう", "す", Rules::empty(), Rules::V5),
+            r(
+                "しましょう",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちましょう", "つ", Rules::empty(), Rules::V5),
             r("にましょう", "ぬ", Rules::empty(), Rules::V5),
             r("びましょう", "ぶ", Rules::empty(), Rules::V5),
             r("みましょう", "む", Rules::empty(), Rules::V5),
-            r("りましょう", "る", Rules::empty(), Rules::V5),
+            r(
+                "りましょう",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じましょう", "ずる", Rules::empty(), Rules::VZ),
             r("しましょう", "する", Rules::empty(), Rules::VS),
             r("為ましょう", "為る", Rules::empty(), Rules::VS),
             r("きましょう", "くる", Rules::empty(), Rules::VK),
             r("来ましょう", "来る", Rules::empty(), Rules::VK),
             r("來ましょう", "來る", Rules::empty(), Rules::VK),
-        ],
+            r("さいましょう", "さる", Rules::empty(), Rules::V5ARU),
+            r("しゃいましょう", "しゃる", Rules::empty(), Rules::V5ARU),
+            r("ざいましょう", "ざる", Rules::empty(), Rules::V5ARU),
+        ]),
     },
     InflectionRules {
         reason: Reasons::ZU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ず", "る", Rules::empty(), Rules::V1),
-            r("かず", "く", Rules::empty(), Rules::V5),
+            r(
+                "かず",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("がず", "ぐ", Rules::empty(), Rules::V5),
-            r("さず", "す", Rules::empty(), Rules::V5),
+            r(
+                "さず",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("たず", "つ", Rules::empty(), Rules::V5),
             r("なず", "ぬ", Rules::empty(), Rules::V5),
             r("ばず", "ぶ", Rules::empty(), Rules::V5),
             r("まず", "む", Rules::empty(), Rules::V5),
-            r("らず", "る", Rules::empty(), Rules::V5),
-            r("わず", "う", Rules::empty(), Rules::V5),
+            r(
+                "らず",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わず",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
             r("ぜず", "ずる", Rules::empty(), Rules::VZ),
             r("せず", "する", Rules::empty(), Rules::VS),
             r("為ず", "為る", Rules::empty(), Rules::VS),
             r("こず", "くる", Rules::empty(), Rules::VK),
             r("来ず", "来る", Rules::empty(), Rules::VK),
             r("來ず", "來る", Rules::empty(), Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::CAUSATIVE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("させる", "る", Rules::V1, Rules::V1),
-            r("かせる", "く", Rules::V1, Rules::V5),
+            r(
+                "かせる",
+                "く",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("がせる", "ぐ", Rules::V1, Rules::V5),
-            r("させる", "す", Rules::V1, Rules::V5),
+            r(
+                "させる",
+                "す",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("たせる", "つ", Rules::V1, Rules::V5),
             r("なせる", "ぬ", Rules::V1, Rules::V5),
             r("ばせる", "ぶ", Rules::V1, Rules::V5),
             r("ませる", "む", Rules::V1, Rules::V5),
-            r("らせる", "る", Rules::V1, Rules::V5),
-            r("わせる", "う", Rules::V1, Rules::V5),
+            r(
+                "らせる",
+                "る",
+                Rules::V1,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わせる",
+                "う",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
             r("じさせる", "ずる", Rules::V1, Rules::VZ),
             r("ぜさせる", "ずる", Rules::V1, Rules::VZ),
             r("させる", "する", Rules::V1, Rules::VS),
@@ -583,69 +1147,137 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("こさせる", "くる", Rules::V1, Rules::VK),
             r("来させる", "来る", Rules::V1, Rules::VK),
             r("來させる", "來る", Rules::V1, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::POLITE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ます", "る", Rules::empty(), Rules::V1),
-            r("います", "う", Rules::empty(), Rules::V5),
-            r("きます", "く", Rules::empty(), Rules::V5),
+            r(
+                "います",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きます",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎます", "ぐ", Rules::empty(), Rules::V5),
-            r("します", "す", Rules::empty(), Rules::V5),
-            r("ちます", "つ", Rules::empty(), Rules::V5),
-            r("にます", "ぬ", Rules::empty(), Rules::V5),
+            r(
+                "します",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちます", "つ", Rules::empty(), Rules::V5),
+            r("にます", "ぬ", Rules::empty(), Rules::V5),
             r("びます", "ぶ", Rules::empty(), Rules::V5),
             r("みます", "む", Rules::empty(), Rules::V5),
-            r("ります", "る", Rules::empty(), Rules::V5),
+            r(
+                "ります",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じます", "ずる", Rules::empty(), Rules::VZ),
             r("します", "する", Rules::empty(), Rules::VS),
             r("為ます", "為る", Rules::empty(), Rules::VS),
             r("きます", "くる", Rules::empty(), Rules::VK),
             r("来ます", "来る", Rules::empty(), Rules::VK),
             r("來ます", "來る", Rules::empty(), Rules::VK),
-        ],
+            r("です", "だ", Rules::empty(), Rules::COP),
+            r("さいます", "さる", Rules::empty(), Rules::V5ARU),
+            r("しゃいます", "しゃる", Rules::empty(), Rules::V5ARU),
+            r("ざいます", "ざる", Rules::empty(), Rules::V5ARU),
+        ]),
     },
     InflectionRules {
         reason: Reasons::KI,
-        rules: &[r("き", "い", Rules::empty(), Rules::ADJ_I)],
+        rules: Cow::Borrowed(&[r("き", "い", Rules::empty(), Rules::ADJ_I)]),
     },
     InflectionRules {
         reason: Reasons::SOU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("そう", "い", Rules::empty(), Rules::ADJ_I),
             r("そう", "る", Rules::empty(), Rules::V1),
-            r("いそう", "う", Rules::empty(), Rules::V5),
-            r("きそう", "く", Rules::empty(), Rules::V5),
+            r(
+                "いそう",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きそう",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎそう", "ぐ", Rules::empty(), Rules::V5),
-            r("しそう", "す", Rules::empty(), Rules::V5),
+            r(
+                "しそう",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちそう", "つ", Rules::empty(), Rules::V5),
             r("にそう", "ぬ", Rules::empty(), Rules::V5),
             r("びそう", "ぶ", Rules::empty(), Rules::V5),
             r("みそう", "む", Rules::empty(), Rules::V5),
-            r("りそう", "る", Rules::empty(), Rules::V5),
+            r(
+                "りそう",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じそう", "ずる", Rules::empty(), Rules::VZ),
             r("しそう", "する", Rules::empty(), Rules::VS),
             r("為そう", "為る", Rules::empty(), Rules::VS),
             r("きそう", "くる", Rules::empty(), Rules::VK),
             r("来そう", "来る", Rules::empty(), Rules::VK),
             r("來そう", "來る", Rules::empty(), Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::IMPERATIVE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ろ", "る", Rules::empty(), Rules::V1),
             r("よ", "る", Rules::empty(), Rules::V1),
-            r("え", "う", Rules::empty(), Rules::V5),
-            r("け", "く", Rules::empty(), Rules::V5),
+            r(
+                "え",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "け",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("げ", "ぐ", Rules::empty(), Rules::V5),
-            r("せ", "す", Rules::empty(), Rules::V5),
+            r(
+                "せ",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("て", "つ", Rules::empty(), Rules::V5),
             r("ね", "ぬ", Rules::empty(), Rules::V5),
             r("べ", "ぶ", Rules::empty(), Rules::V5),
             r("め", "む", Rules::empty(), Rules::V5),
-            r("れ", "る", Rules::empty(), Rules::V5),
+            r(
+                "れ",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5R_I.bits()),
+            ),
             r("じろ", "ずる", Rules::empty(), Rules::VZ),
             r("ぜよ", "ずる", Rules::empty(), Rules::VZ),
             r("しろ", "する", Rules::empty(), Rules::VS),
@@ -655,11 +1287,13 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("こい", "くる", Rules::empty(), Rules::VK),
             r("来い", "来る", Rules::empty(), Rules::VK),
             r("來い", "來る", Rules::empty(), Rules::VK),
-        ],
+            r("さい", "さる", Rules::empty(), Rules::V5ARU),
+            r("しゃい", "しゃる", Rules::empty(), Rules::V5ARU),
+        ]),
     },
     InflectionRules {
         reason: Reasons::E,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ねえ", "ない", Rules::empty(), Rules::ADJ_I),
             r("めえ", "むい", Rules::empty(), Rules::ADJ_I),
             r("みい", "むい", Rules::empty(), Rules::ADJ_I),
@@ -684,32 +1318,54 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("でえ", "どい", Rules::empty(), Rules::ADJ_I),
             r("れえ", "れい", Rules::empty(), Rules::ADJ_I),
             r("べえ", "ばい", Rules::empty(), Rules::ADJ_I),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::NU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ぬ", "る", Rules::empty(), Rules::V1),
-            r("かぬ", "く", Rules::empty(), Rules::V5),
+            r(
+                "かぬ",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("がぬ", "ぐ", Rules::empty(), Rules::V5),
-            r("さぬ", "す", Rules::empty(), Rules::V5),
+            r(
+                "さぬ",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("たぬ", "つ", Rules::empty(), Rules::V5),
             r("なぬ", "ぬ", Rules::empty(), Rules::V5),
             r("ばぬ", "ぶ", Rules::empty(), Rules::V5),
             r("まぬ", "む", Rules::empty(), Rules::V5),
-            r("らぬ", "る", Rules::empty(), Rules::V5),
-            r("わぬ", "う", Rules::empty(), Rules::V5),
+            r(
+                "らぬ",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わぬ",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
             r("ぜぬ", "ずる", Rules::empty(), Rules::VZ),
             r("せぬ", "する", Rules::empty(), Rules::VS),
             r("為ぬ", "為る", Rules::empty(), Rules::VS),
             r("こぬ", "くる", Rules::empty(), Rules::VK),
             r("来ぬ", "来る", Rules::empty(), Rules::VK),
             r("來ぬ", "來る", Rules::empty(), Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::POTENTIAL_OR_PASSIVE,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("られる", "る", Rules::V1, Rules::V1),
             r("ざれる", "ずる", Rules::V1, Rules::VZ),
             r("ぜられる", "ずる", Rules::V1, Rules::VZ),
@@ -718,55 +1374,1685 @@ pub const INFLECTION_RULES: &[InflectionRules] = &[
             r("こられる", "くる", Rules::V1, Rules::VK),
             r("来られる", "来る", Rules::V1, Rules::VK),
             r("來られる", "來る", Rules::V1, Rules::VK),
-        ],
+        ]),
     },
     InflectionRules {
         reason: Reasons::ADV,
-        rules: &[r("く", "い", Rules::empty(), Rules::ADJ_I)],
+        rules: Cow::Borrowed(&[
+            r("く", "い", Rules::empty(), Rules::ADJ_I),
+            r("に", "", Rules::empty(), Rules::ADJ_NA),
+        ]),
     },
     InflectionRules {
         reason: Reasons::NOUN,
-        rules: &[r("さ", "い", Rules::empty(), Rules::ADJ_I)],
+        rules: Cow::Borrowed(&[
+            r("さ", "い", Rules::empty(), Rules::ADJ_I),
+            r("さ", "", Rules::empty(), Rules::ADJ_NA),
+        ]),
     },
     InflectionRules {
         reason: Reasons::POLITE_PAST,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("ました", "る", Rules::empty(), Rules::V1),
-            r("いました", "う", Rules::empty(), Rules::V5),
-            r("きました", "く", Rules::empty(), Rules::V5),
+            r(
+                "いました",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きました",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("ぎました", "ぐ", Rules::empty(), Rules::V5),
-            r("しました", "す", Rules::empty(), Rules::V5),
+            r(
+                "しました",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
             r("ちました", "つ", Rules::empty(), Rules::V5),
             r("にました", "ぬ", Rules::empty(), Rules::V5),
             r("びました", "ぶ", Rules::empty(), Rules::V5),
             r("みました", "む", Rules::empty(), Rules::V5),
-            r("りました", "る", Rules::empty(), Rules::V5),
+            r(
+                "りました",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
             r("じました", "ずる", Rules::empty(), Rules::VZ),
             r("しました", "する", Rules::empty(), Rules::VS),
             r("為ました", "為る", Rules::empty(), Rules::VS),
             r("きました", "くる", Rules::empty(), Rules::VK),
             r("来ました", "来る", Rules::empty(), Rules::VK),
             r("來ました", "來る", Rules::empty(), Rules::VK),
-        ],
+            r("でした", "だ", Rules::empty(), Rules::COP),
+            r("さいました", "さる", Rules::empty(), Rules::V5ARU),
+            r("しゃいました", "しゃる", Rules::empty(), Rules::V5ARU),
+            r("ざいました", "ざる", Rules::empty(), Rules::V5ARU),
+        ]),
     },
     InflectionRules {
         reason: Reasons::CAUSATIVE_PASSIVE,
-        rules: &[
-            r("かされる", "く", Rules::V1, Rules::V5),
+        rules: Cow::Borrowed(&[
+            r(
+                "かされる",
+                "く",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
             r("がされる", "ぐ", Rules::V1, Rules::V5),
             r("たされる", "つ", Rules::V1, Rules::V5),
             r("なされる", "ぬ", Rules::V1, Rules::V5),
             r("ばされる", "ぶ", Rules::V1, Rules::V5),
             r("まされる", "む", Rules::V1, Rules::V5),
-            r("らされる", "る", Rules::V1, Rules::V5),
-            r("わされる", "う", Rules::V1, Rules::V5),
-        ],
+            r(
+                "らされる",
+                "る",
+                Rules::V1,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わされる",
+                "う",
+                Rules::V1,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("さされる", "る", Rules::V1, Rules::V1),
+            r("さされる", "する", Rules::V1, Rules::VS),
+            r("こさされる", "くる", Rules::V1, Rules::VK),
+            r("来さされる", "来る", Rules::V1, Rules::VK),
+            r("來さされる", "來る", Rules::V1, Rules::VK),
+        ]),
     },
     InflectionRules {
         reason: Reasons::SHIMAU,
-        rules: &[
+        rules: Cow::Borrowed(&[
             r("てしまう", "て", Rules::V5, Rules::IRU),
             r("でしまう", "で", Rules::V5, Rules::IRU),
-        ],
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::COPULA,
+        rules: Cow::Borrowed(&[r("だ", "", Rules::COP, Rules::ADJ_NA)]),
+    },
+    InflectionRules {
+        reason: Reasons::ATTRIBUTIVE,
+        rules: Cow::Borrowed(&[r("な", "", Rules::empty(), Rules::ADJ_NA)]),
+    },
+    InflectionRules {
+        reason: Reasons::HONORIFIC,
+        rules: Cow::Borrowed(&[
+            rp("お", "", "になる", "る", Rules::V5, Rules::V1),
+            rp(
+                "お",
+                "",
+                "いになる",
+                "う",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            rp(
+                "お",
+                "",
+                "きになる",
+                "く",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            rp("お", "", "ぎになる", "ぐ", Rules::V5, Rules::V5),
+            rp(
+                "お",
+                "",
+                "しになる",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            rp("お", "", "ちになる", "つ", Rules::V5, Rules::V5),
+            rp("お", "", "にになる", "ぬ", Rules::V5, Rules::V5),
+            rp("お", "", "びになる", "ぶ", Rules::V5, Rules::V5),
+            rp("お", "", "みになる", "む", Rules::V5, Rules::V5),
+            rp(
+                "お",
+                "",
+                "りになる",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            rp("お", "", "になる", "する", Rules::V5, Rules::VS),
+            rp("ご", "", "になる", "する", Rules::V5, Rules::VS),
+            rp("ご覧", "見", "になる", "る", Rules::V5, Rules::V1),
+            rp("お", "", "くださる", "る", Rules::V5ARU, Rules::V1),
+            rp(
+                "お",
+                "",
+                "いくださる",
+                "う",
+                Rules::V5ARU,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            rp(
+                "お",
+                "",
+                "きくださる",
+                "く",
+                Rules::V5ARU,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            rp("お", "", "ぎくださる", "ぐ", Rules::V5ARU, Rules::V5),
+            rp(
+                "お",
+                "",
+                "しくださる",
+                "す",
+                Rules::V5ARU,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            rp("お", "", "ちくださる", "つ", Rules::V5ARU, Rules::V5),
+            rp("お", "", "にくださる", "ぬ", Rules::V5ARU, Rules::V5),
+            rp("お", "", "びくださる", "ぶ", Rules::V5ARU, Rules::V5),
+            rp("お", "", "みくださる", "む", Rules::V5ARU, Rules::V5),
+            rp(
+                "お",
+                "",
+                "りくださる",
+                "る",
+                Rules::V5ARU,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            rp("お", "", "くださる", "する", Rules::V5ARU, Rules::VS),
+            rp("ご", "", "くださる", "する", Rules::V5ARU, Rules::VS),
+            rp("ご覧", "見", "くださる", "る", Rules::V5ARU, Rules::V1),
+            rp("お", "", "なさる", "る", Rules::V5ARU, Rules::V1),
+            rp(
+                "お",
+                "",
+                "いなさる",
+                "う",
+                Rules::V5ARU,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            rp(
+                "お",
+                "",
+                "きなさる",
+                "く",
+                Rules::V5ARU,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            rp("お", "", "ぎなさる", "ぐ", Rules::V5ARU, Rules::V5),
+            rp(
+                "お",
+                "",
+                "しなさる",
+                "す",
+                Rules::V5ARU,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            rp("お", "", "ちなさる", "つ", Rules::V5ARU, Rules::V5),
+            rp("お", "", "になさる", "ぬ", Rules::V5ARU, Rules::V5),
+            rp("お", "", "びなさる", "ぶ", Rules::V5ARU, Rules::V5),
+            rp("お", "", "みなさる", "む", Rules::V5ARU, Rules::V5),
+            rp(
+                "お",
+                "",
+                "りなさる",
+                "る",
+                Rules::V5ARU,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            rp("お", "", "なさる", "する", Rules::V5ARU, Rules::VS),
+            rp("ご", "", "なさる", "する", Rules::V5ARU, Rules::VS),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::HUMBLE,
+        rules: Cow::Borrowed(&[
+            rp("お", "", "する", "る", Rules::VS, Rules::V1),
+            rp(
+                "お",
+                "",
+                "いする",
+                "う",
+                Rules::VS,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            rp(
+                "お",
+                "",
+                "きする",
+                "く",
+                Rules::VS,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            rp("お", "", "ぎする", "ぐ", Rules::VS, Rules::V5),
+            rp(
+                "お",
+                "",
+                "しする",
+                "す",
+                Rules::VS,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            rp("お", "", "ちする", "つ", Rules::VS, Rules::V5),
+            rp("お", "", "にする", "ぬ", Rules::VS, Rules::V5),
+            rp("お", "", "びする", "ぶ", Rules::VS, Rules::V5),
+            rp("お", "", "みする", "む", Rules::VS, Rules::V5),
+            rp(
+                "お",
+                "",
+                "りする",
+                "る",
+                Rules::VS,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            rp("お", "", "する", "する", Rules::VS, Rules::VS),
+            rp("ご", "", "する", "する", Rules::VS, Rules::VS),
+            rp("お", "", "いたす", "る", Rules::V5, Rules::V1),
+            rp(
+                "お",
+                "",
+                "いいたす",
+                "う",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            rp(
+                "お",
+                "",
+                "きいたす",
+                "く",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            rp("お", "", "ぎいたす", "ぐ", Rules::V5, Rules::V5),
+            rp(
+                "お",
+                "",
+                "しいたす",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            rp("お", "", "ちいたす", "つ", Rules::V5, Rules::V5),
+            rp("お", "", "にいたす", "ぬ", Rules::V5, Rules::V5),
+            rp("お", "", "びいたす", "ぶ", Rules::V5, Rules::V5),
+            rp("お", "", "みいたす", "む", Rules::V5, Rules::V5),
+            rp(
+                "お",
+                "",
+                "りいたす",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            rp("お", "", "いたす", "する", Rules::V5, Rules::VS),
+            rp("ご", "", "いたす", "する", Rules::V5, Rules::VS),
+            r("させていただく", "る", Rules::V5, Rules::V1),
+            r(
+                "かせていただく",
+                "く",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がせていただく", "ぐ", Rules::V5, Rules::V5),
+            r(
+                "させていただく",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たせていただく", "つ", Rules::V5, Rules::V5),
+            r("なせていただく", "ぬ", Rules::V5, Rules::V5),
+            r("ばせていただく", "ぶ", Rules::V5, Rules::V5),
+            r("ませていただく", "む", Rules::V5, Rules::V5),
+            r(
+                "らせていただく",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わせていただく",
+                "う",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("じさせていただく", "ずる", Rules::V5, Rules::VZ),
+            r("ぜさせていただく", "ずる", Rules::V5, Rules::VZ),
+            r("させていただく", "する", Rules::V5, Rules::VS),
+            r("為せていただく", "為る", Rules::V5, Rules::VS),
+            r("せさせていただく", "する", Rules::V5, Rules::VS),
+            r("為させていただく", "為る", Rules::V5, Rules::VS),
+            r("こさせていただく", "くる", Rules::V5, Rules::VK),
+            r("来させていただく", "来る", Rules::V5, Rules::VK),
+            r("來させていただく", "來る", Rules::V5, Rules::VK),
+            r("させて頂く", "る", Rules::V5, Rules::V1),
+            r(
+                "かせて頂く",
+                "く",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がせて頂く", "ぐ", Rules::V5, Rules::V5),
+            r(
+                "させて頂く",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たせて頂く", "つ", Rules::V5, Rules::V5),
+            r("なせて頂く", "ぬ", Rules::V5, Rules::V5),
+            r("ばせて頂く", "ぶ", Rules::V5, Rules::V5),
+            r("ませて頂く", "む", Rules::V5, Rules::V5),
+            r(
+                "らせて頂く",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わせて頂く",
+                "う",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("じさせて頂く", "ずる", Rules::V5, Rules::VZ),
+            r("ぜさせて頂く", "ずる", Rules::V5, Rules::VZ),
+            r("させて頂く", "する", Rules::V5, Rules::VS),
+            r("為せて頂く", "為る", Rules::V5, Rules::VS),
+            r("せさせて頂く", "する", Rules::V5, Rules::VS),
+            r("為させて頂く", "為る", Rules::V5, Rules::VS),
+            r("こさせて頂く", "くる", Rules::V5, Rules::VK),
+            r("来させて頂く", "来る", Rules::V5, Rules::VK),
+            r("來させて頂く", "來る", Rules::V5, Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::TAGARU,
+        rules: Cow::Borrowed(&[
+            r("たがる", "る", Rules::V5, Rules::V1),
+            r(
+                "いたがる",
+                "う",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きたがる",
+                "く",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎたがる", "ぐ", Rules::V5, Rules::V5),
+            r(
+                "したがる",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちたがる", "つ", Rules::V5, Rules::V5),
+            r("にたがる", "ぬ", Rules::V5, Rules::V5),
+            r("びたがる", "ぶ", Rules::V5, Rules::V5),
+            r("みたがる", "む", Rules::V5, Rules::V5),
+            r(
+                "りたがる",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("じたがる", "ずる", Rules::V5, Rules::VZ),
+            r("したがる", "する", Rules::V5, Rules::VS),
+            r("為たがる", "為る", Rules::V5, Rules::VS),
+            r("きたがる", "くる", Rules::V5, Rules::VK),
+            r("来たがる", "来る", Rules::V5, Rules::VK),
+            r("來たがる", "來る", Rules::V5, Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::GARU,
+        rules: Cow::Borrowed(&[r("がる", "い", Rules::V5, Rules::ADJ_I)]),
+    },
+    InflectionRules {
+        reason: Reasons::PPOI,
+        rules: Cow::Borrowed(&[
+            r(
+                "っぽい",
+                "",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::ADJ_NA.bits() | Rules::PAST.bits()),
+            ),
+            r("っぽい", "い", Rules::ADJ_I, Rules::ADJ_I),
+            r("っぽい", "る", Rules::ADJ_I, Rules::V1),
+            r(
+                "いっぽい",
+                "う",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きっぽい",
+                "く",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎっぽい", "ぐ", Rules::ADJ_I, Rules::V5),
+            r(
+                "しっぽい",
+                "す",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちっぽい", "つ", Rules::ADJ_I, Rules::V5),
+            r("にっぽい", "ぬ", Rules::ADJ_I, Rules::V5),
+            r("びっぽい", "ぶ", Rules::ADJ_I, Rules::V5),
+            r("みっぽい", "む", Rules::ADJ_I, Rules::V5),
+            r(
+                "りっぽい",
+                "る",
+                Rules::ADJ_I,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::RASHII,
+        rules: Cow::Borrowed(&[r(
+            "らしい",
+            "",
+            Rules::ADJ_I,
+            Rules::from_bits_retain(
+                Rules::V1.bits()
+                    | Rules::V5.bits()
+                    | Rules::V5S.bits()
+                    | Rules::V5K_S.bits()
+                    | Rules::V5R_I.bits()
+                    | Rules::V5ARU.bits()
+                    | Rules::V5U_S.bits()
+                    | Rules::VS.bits()
+                    | Rules::VK.bits()
+                    | Rules::VZ.bits()
+                    | Rules::ADJ_I.bits()
+                    | Rules::ADJ_NA.bits()
+                    | Rules::PAST.bits(),
+            ),
+        )]),
+    },
+    InflectionRules {
+        reason: Reasons::GACHI,
+        rules: Cow::Borrowed(&[
+            r("がち", "", Rules::ADJ_NA, Rules::ADJ_NA),
+            r("がち", "る", Rules::ADJ_NA, Rules::V1),
+            r(
+                "いがち",
+                "う",
+                Rules::ADJ_NA,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きがち",
+                "く",
+                Rules::ADJ_NA,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎがち", "ぐ", Rules::ADJ_NA, Rules::V5),
+            r(
+                "しがち",
+                "す",
+                Rules::ADJ_NA,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちがち", "つ", Rules::ADJ_NA, Rules::V5),
+            r("にがち", "ぬ", Rules::ADJ_NA, Rules::V5),
+            r("びがち", "ぶ", Rules::ADJ_NA, Rules::V5),
+            r("みがち", "む", Rules::ADJ_NA, Rules::V5),
+            r(
+                "りがち",
+                "る",
+                Rules::ADJ_NA,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("じがち", "ずる", Rules::ADJ_NA, Rules::VZ),
+            r("しがち", "する", Rules::ADJ_NA, Rules::VS),
+            r("為がち", "為る", Rules::ADJ_NA, Rules::VS),
+            r("きがち", "くる", Rules::ADJ_NA, Rules::VK),
+            r("来がち", "来る", Rules::ADJ_NA, Rules::VK),
+            r("來がち", "來る", Rules::ADJ_NA, Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::NAGARA,
+        rules: Cow::Borrowed(&[r("ながら", "", Rules::empty(), Rules::MASU_STEM)]),
+    },
+    InflectionRules {
+        reason: Reasons::TSUTSU,
+        rules: Cow::Borrowed(&[r("つつ", "", Rules::empty(), Rules::MASU_STEM)]),
+    },
+    InflectionRules {
+        reason: Reasons::TEMO,
+        rules: Cow::Borrowed(&[
+            r("ても", "て", Rules::empty(), Rules::IRU),
+            r("でも", "で", Rules::empty(), Rules::IRU),
+            r("でも", "だ", Rules::empty(), Rules::COP),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::TEWA,
+        rules: Cow::Borrowed(&[
+            r("ては", "て", Rules::empty(), Rules::IRU),
+            r("では", "で", Rules::empty(), Rules::IRU),
+            r("では", "だ", Rules::empty(), Rules::COP),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::TATTE,
+        rules: Cow::Borrowed(&[
+            r("たって", "て", Rules::empty(), Rules::IRU),
+            r("だって", "で", Rules::empty(), Rules::IRU),
+            r("だって", "だ", Rules::empty(), Rules::COP),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::OKU,
+        rules: Cow::Borrowed(&[
+            r("ておく", "て", Rules::V5, Rules::IRU),
+            r("でおく", "で", Rules::V5, Rules::IRU),
+            r("て置く", "て", Rules::V5, Rules::IRU),
+            r("で置く", "で", Rules::V5, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::MIRU,
+        rules: Cow::Borrowed(&[
+            r("てみる", "て", Rules::V1, Rules::IRU),
+            r("でみる", "で", Rules::V1, Rules::IRU),
+            r("て見る", "て", Rules::V1, Rules::IRU),
+            r("で見る", "で", Rules::V1, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::ARU,
+        rules: Cow::Borrowed(&[
+            r("てある", "て", Rules::V5R_I, Rules::IRU),
+            r("である", "で", Rules::V5R_I, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KURU,
+        rules: Cow::Borrowed(&[
+            r("てくる", "て", Rules::VK, Rules::IRU),
+            r("でくる", "で", Rules::VK, Rules::IRU),
+            r("て来る", "て", Rules::VK, Rules::IRU),
+            r("で来る", "で", Rules::VK, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::IKU,
+        rules: Cow::Borrowed(&[
+            r("ていく", "て", Rules::V5K_S, Rules::IRU),
+            r("でいく", "で", Rules::V5K_S, Rules::IRU),
+            r("て行く", "て", Rules::V5K_S, Rules::IRU),
+            r("で行く", "で", Rules::V5K_S, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::AGERU,
+        rules: Cow::Borrowed(&[
+            r("てあげる", "て", Rules::V1, Rules::IRU),
+            r("であげる", "で", Rules::V1, Rules::IRU),
+            r("て上げる", "て", Rules::V1, Rules::IRU),
+            r("で上げる", "で", Rules::V1, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KURERU,
+        rules: Cow::Borrowed(&[
+            r("てくれる", "て", Rules::V1, Rules::IRU),
+            r("でくれる", "で", Rules::V1, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::MORAU,
+        rules: Cow::Borrowed(&[
+            r("てもらう", "て", Rules::V5, Rules::IRU),
+            r("でもらう", "で", Rules::V5, Rules::IRU),
+            r("て貰う", "て", Rules::V5, Rules::IRU),
+            r("で貰う", "で", Rules::V5, Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::OBLIGATION,
+        rules: Cow::Borrowed(&[
+            r("なければならない", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なければなりません", "ない", Rules::empty(), Rules::ADJ_I),
+            r(
+                "なければなりませんでした",
+                "ない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("なければいけない", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なければいけません", "ない", Rules::empty(), Rules::ADJ_I),
+            r(
+                "なければいけませんでした",
+                "ない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("なくてはならない", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なくてはなりません", "ない", Rules::empty(), Rules::ADJ_I),
+            r(
+                "なくてはなりませんでした",
+                "ない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("なくてはいけない", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なくてはいけません", "ない", Rules::empty(), Rules::ADJ_I),
+            r(
+                "なくてはいけませんでした",
+                "ない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("ないといけない", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("ないといけません", "ない", Rules::empty(), Rules::ADJ_I),
+            r(
+                "ないといけませんでした",
+                "ない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("でなければならない", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "でなければなりません",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r(
+                "でなければなりませんでした",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("でなければいけない", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "でなければいけません",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r(
+                "でなければいけませんでした",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("でなくてはならない", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "でなくてはなりません",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r(
+                "でなくてはなりませんでした",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("でなくてはいけない", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "でなくてはいけません",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r(
+                "でなくてはいけませんでした",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("でないといけない", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "でないといけません",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r(
+                "でないといけませんでした",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r(
+                "べき",
+                "",
+                Rules::ADJ_NA,
+                Rules::from_bits_retain(
+                    Rules::V1.bits()
+                        | Rules::V5.bits()
+                        | Rules::V5S.bits()
+                        | Rules::VK.bits()
+                        | Rules::VZ.bits()
+                        | Rules::V5K_S.bits()
+                        | Rules::V5R_I.bits()
+                        | Rules::V5ARU.bits()
+                        | Rules::V5U_S.bits(),
+                ),
+            ),
+            r("すべき", "する", Rules::ADJ_NA, Rules::VS),
+            r("するべき", "する", Rules::ADJ_NA, Rules::VS),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::NO_OBLIGATION,
+        rules: Cow::Borrowed(&[
+            r("なくてもいい", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なくてもよい", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なくても良い", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なくても構わない", "ない", Rules::ADJ_I, Rules::ADJ_I),
+            r("なくても構いません", "ない", Rules::empty(), Rules::ADJ_I),
+            r(
+                "なくても構いませんでした",
+                "ない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r("でなくてもいい", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r("でなくてもよい", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r("でなくても良い", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r("でなくても構わない", "ではない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "でなくても構いません",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+            r(
+                "でなくても構いませんでした",
+                "ではない",
+                Rules::empty(),
+                Rules::ADJ_I,
+            ),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::PERMISSION,
+        rules: Cow::Borrowed(&[
+            r("てもいい", "て", Rules::ADJ_I, Rules::IRU),
+            r("てもよい", "て", Rules::ADJ_I, Rules::IRU),
+            r("ても良い", "て", Rules::ADJ_I, Rules::IRU),
+            r("ても構わない", "て", Rules::ADJ_I, Rules::IRU),
+            r("ても構いません", "て", Rules::empty(), Rules::IRU),
+            r("ても構いませんでした", "て", Rules::empty(), Rules::IRU),
+            r("でもいい", "で", Rules::ADJ_I, Rules::IRU),
+            r("でもよい", "で", Rules::ADJ_I, Rules::IRU),
+            r("でも良い", "で", Rules::ADJ_I, Rules::IRU),
+            r("でも構わない", "で", Rules::ADJ_I, Rules::IRU),
+            r("でも構いません", "で", Rules::empty(), Rules::IRU),
+            r("でも構いませんでした", "で", Rules::empty(), Rules::IRU),
+            r("でもいい", "だ", Rules::ADJ_I, Rules::COP),
+            r("でもよい", "だ", Rules::ADJ_I, Rules::COP),
+            r("でも良い", "だ", Rules::ADJ_I, Rules::COP),
+            r("でも構わない", "だ", Rules::ADJ_I, Rules::COP),
+            r("でも構いません", "だ", Rules::empty(), Rules::COP),
+            r("でも構いませんでした", "だ", Rules::empty(), Rules::COP),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::PROHIBITION,
+        rules: Cow::Borrowed(&[
+            r("てはならない", "て", Rules::ADJ_I, Rules::IRU),
+            r("てはなりません", "て", Rules::empty(), Rules::IRU),
+            r("てはなりませんでした", "て", Rules::empty(), Rules::IRU),
+            r("てはいけない", "て", Rules::ADJ_I, Rules::IRU),
+            r("てはいけません", "て", Rules::empty(), Rules::IRU),
+            r("てはいけませんでした", "て", Rules::empty(), Rules::IRU),
+            r("てはだめ", "て", Rules::ADJ_NA, Rules::IRU),
+            r("ては駄目", "て", Rules::ADJ_NA, Rules::IRU),
+            r("ではならない", "で", Rules::ADJ_I, Rules::IRU),
+            r("ではなりません", "で", Rules::empty(), Rules::IRU),
+            r("ではなりませんでした", "で", Rules::empty(), Rules::IRU),
+            r("ではいけない", "で", Rules::ADJ_I, Rules::IRU),
+            r("ではいけません", "で", Rules::empty(), Rules::IRU),
+            r("ではいけませんでした", "で", Rules::empty(), Rules::IRU),
+            r("ではだめ", "で", Rules::ADJ_NA, Rules::IRU),
+            r("では駄目", "で", Rules::ADJ_NA, Rules::IRU),
+            r("ではならない", "だ", Rules::ADJ_I, Rules::COP),
+            r("ではなりません", "だ", Rules::empty(), Rules::COP),
+            r("ではなりませんでした", "だ", Rules::empty(), Rules::COP),
+            r("ではいけない", "だ", Rules::ADJ_I, Rules::COP),
+            r("ではいけません", "だ", Rules::empty(), Rules::COP),
+            r("ではいけませんでした", "だ", Rules::empty(), Rules::COP),
+            r("ではだめ", "だ", Rules::ADJ_NA, Rules::COP),
+            r("では駄目", "だ", Rules::ADJ_NA, Rules::COP),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::REQUEST,
+        rules: Cow::Borrowed(&[
+            r("てください", "て", Rules::empty(), Rules::IRU),
+            r("て下さい", "て", Rules::empty(), Rules::IRU),
+            r("てくださいませ", "て", Rules::empty(), Rules::IRU),
+            r("て下さいませ", "て", Rules::empty(), Rules::IRU),
+            r("てくれ", "て", Rules::empty(), Rules::IRU),
+            r("てちょうだい", "て", Rules::empty(), Rules::IRU),
+            r("て頂戴", "て", Rules::empty(), Rules::IRU),
+            r("でください", "で", Rules::empty(), Rules::IRU),
+            r("で下さい", "で", Rules::empty(), Rules::IRU),
+            r("でくださいませ", "で", Rules::empty(), Rules::IRU),
+            r("で下さいませ", "で", Rules::empty(), Rules::IRU),
+            r("でくれ", "で", Rules::empty(), Rules::IRU),
+            r("でちょうだい", "で", Rules::empty(), Rules::IRU),
+            r("で頂戴", "で", Rules::empty(), Rules::IRU),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::NEGATIVE_REQUEST,
+        rules: Cow::Borrowed(&[
+            r("ないでください", "ない", Rules::empty(), Rules::ADJ_I),
+            r("ないで下さい", "ない", Rules::empty(), Rules::ADJ_I),
+            r("ないでくださいませ", "ない", Rules::empty(), Rules::ADJ_I),
+            r("ないで下さいませ", "ない", Rules::empty(), Rules::ADJ_I),
+            r("ないでくれ", "ない", Rules::empty(), Rules::ADJ_I),
+            r("ないでちょうだい", "ない", Rules::empty(), Rules::ADJ_I),
+            r("ないで頂戴", "ない", Rules::empty(), Rules::ADJ_I),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::SHORT_CAUSATIVE,
+        rules: Cow::Borrowed(&[
+            r("さす", "る", Rules::V5S, Rules::V1),
+            r(
+                "かす",
+                "く",
+                Rules::V5S,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がす", "ぐ", Rules::V5S, Rules::V5),
+            r("さす", "す", Rules::V5S, Rules::V5),
+            r("たす", "つ", Rules::V5S, Rules::V5),
+            r("なす", "ぬ", Rules::V5S, Rules::V5),
+            r("ばす", "ぶ", Rules::V5S, Rules::V5),
+            r("ます", "む", Rules::V5S, Rules::V5),
+            r(
+                "らす",
+                "る",
+                Rules::V5S,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わす",
+                "う",
+                Rules::V5S,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("じさす", "ずる", Rules::V5S, Rules::VZ),
+            r("さす", "する", Rules::V5S, Rules::VS),
+            r("こさす", "くる", Rules::V5S, Rules::VK),
+            r("来さす", "来る", Rules::V5S, Rules::VK),
+            r("來さす", "來る", Rules::V5S, Rules::VK),
+        ]),
+    },
+];
+
+/// Contractions of spoken Japanese like 行かなきゃ, 見ちゃ and 知らん.
+pub static COLLOQUIAL_RULES: &[InflectionRules] = &[
+    InflectionRules {
+        reason: Reasons::NAKYA,
+        rules: Cow::Borrowed(&[
+            r("なきゃ", "ない", Rules::empty(), Rules::ADJ_I),
+            r("なけりゃ", "ない", Rules::empty(), Rules::ADJ_I),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::NAKUCHA,
+        rules: Cow::Borrowed(&[
+            r("なくちゃ", "ない", Rules::empty(), Rules::ADJ_I),
+            r("なくっちゃ", "ない", Rules::empty(), Rules::ADJ_I),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::CHA,
+        rules: Cow::Borrowed(&[
+            r("ちゃ", "て", Rules::empty(), Rules::IRU),
+            r("じゃ", "で", Rules::empty(), Rules::IRU),
+            r("ちゃだめ", "てはだめ", Rules::ADJ_NA, Rules::ADJ_NA),
+            r("ちゃ駄目", "ては駄目", Rules::ADJ_NA, Rules::ADJ_NA),
+            r("ちゃいけない", "てはいけない", Rules::ADJ_I, Rules::ADJ_I),
+            r("ちゃならない", "てはならない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "ちゃいけません",
+                "てはいけません",
+                Rules::empty(),
+                Rules::empty(),
+            ),
+            r(
+                "ちゃなりません",
+                "てはなりません",
+                Rules::empty(),
+                Rules::empty(),
+            ),
+            r("じゃだめ", "ではだめ", Rules::ADJ_NA, Rules::ADJ_NA),
+            r("じゃ駄目", "では駄目", Rules::ADJ_NA, Rules::ADJ_NA),
+            r("じゃいけない", "ではいけない", Rules::ADJ_I, Rules::ADJ_I),
+            r("じゃならない", "ではならない", Rules::ADJ_I, Rules::ADJ_I),
+            r(
+                "じゃいけません",
+                "ではいけません",
+                Rules::empty(),
+                Rules::empty(),
+            ),
+            r(
+                "じゃなりません",
+                "ではなりません",
+                Rules::empty(),
+                Rules::empty(),
+            ),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::N,
+        rules: Cow::Borrowed(&[
+            r("いん", "いる", Rules::empty(), Rules::V1),
+            r("えん", "える", Rules::empty(), Rules::V1),
+            r("きん", "きる", Rules::empty(), Rules::V1),
+            r("ぎん", "ぎる", Rules::empty(), Rules::V1),
+            r("けん", "ける", Rules::empty(), Rules::V1),
+            r("げん", "げる", Rules::empty(), Rules::V1),
+            r("じん", "じる", Rules::empty(), Rules::V1),
+            r("せん", "せる", Rules::empty(), Rules::V1),
+            r("ぜん", "ぜる", Rules::empty(), Rules::V1),
+            r("ちん", "ちる", Rules::empty(), Rules::V1),
+            r("てん", "てる", Rules::empty(), Rules::V1),
+            r("でん", "でる", Rules::empty(), Rules::V1),
+            r("にん", "にる", Rules::empty(), Rules::V1),
+            r("ねん", "ねる", Rules::empty(), Rules::V1),
+            r("ひん", "ひる", Rules::empty(), Rules::V1),
+            r("びん", "びる", Rules::empty(), Rules::V1),
+            r("へん", "へる", Rules::empty(), Rules::V1),
+            r("べん", "べる", Rules::empty(), Rules::V1),
+            r("みん", "みる", Rules::empty(), Rules::V1),
+            r("めん", "める", Rules::empty(), Rules::V1),
+            r("りん", "りる", Rules::empty(), Rules::V1),
+            r("れん", "れる", Rules::empty(), Rules::V1),
+            r(
+                "かん",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がん", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "さん",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たん", "つ", Rules::empty(), Rules::V5),
+            r("なん", "ぬ", Rules::empty(), Rules::V5),
+            r("ばん", "ぶ", Rules::empty(), Rules::V5),
+            r("まん", "む", Rules::empty(), Rules::V5),
+            r(
+                "らん",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5ARU.bits() | Rules::V5R_I.bits(),
+                ),
+            ),
+            r(
+                "わん",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("せん", "する", Rules::empty(), Rules::VS),
+            r("こん", "くる", Rules::empty(), Rules::VK),
+            r("来ん", "来る", Rules::empty(), Rules::VK),
+            r("んない", "らない", Rules::ADJ_I, Rules::ADJ_I),
+        ]),
+    },
+];
+
+/// Auxiliaries and adjective forms of classical Japanese like 行かざる, 行くべし and 高からず, deinflected to the modern dictionary form.
+pub static CLASSICAL_RULES: &[InflectionRules] = &[
+    InflectionRules {
+        reason: Reasons::ZARU,
+        rules: Cow::Borrowed(&[
+            r("ざる", "る", Rules::empty(), Rules::V1),
+            r(
+                "かざる",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がざる", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "さざる",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たざる", "つ", Rules::empty(), Rules::V5),
+            r("なざる", "ぬ", Rules::empty(), Rules::V5),
+            r("ばざる", "ぶ", Rules::empty(), Rules::V5),
+            r("まざる", "む", Rules::empty(), Rules::V5),
+            r(
+                "らざる",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わざる",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("ぜざる", "ずる", Rules::empty(), Rules::VZ),
+            r("せざる", "する", Rules::empty(), Rules::VS),
+            r("為ざる", "為る", Rules::empty(), Rules::VS),
+            r("こざる", "くる", Rules::empty(), Rules::VK),
+            r("来ざる", "来る", Rules::empty(), Rules::VK),
+            r("來ざる", "來る", Rules::empty(), Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::BESHI,
+        rules: Cow::Borrowed(&[
+            r(
+                "べし",
+                "",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V1.bits()
+                        | Rules::V5.bits()
+                        | Rules::V5S.bits()
+                        | Rules::V5K_S.bits()
+                        | Rules::V5R_I.bits()
+                        | Rules::V5ARU.bits()
+                        | Rules::V5U_S.bits()
+                        | Rules::VS.bits()
+                        | Rules::VK.bits()
+                        | Rules::VZ.bits(),
+                ),
+            ),
+            r("すべし", "する", Rules::empty(), Rules::VS),
+            r(
+                "べく",
+                "",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V1.bits()
+                        | Rules::V5.bits()
+                        | Rules::V5S.bits()
+                        | Rules::V5K_S.bits()
+                        | Rules::V5R_I.bits()
+                        | Rules::V5ARU.bits()
+                        | Rules::V5U_S.bits()
+                        | Rules::VS.bits()
+                        | Rules::VK.bits()
+                        | Rules::VZ.bits(),
+                ),
+            ),
+            r("すべく", "する", Rules::empty(), Rules::VS),
+            r(
+                "べからず",
+                "",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V1.bits()
+                        | Rules::V5.bits()
+                        | Rules::V5S.bits()
+                        | Rules::V5K_S.bits()
+                        | Rules::V5R_I.bits()
+                        | Rules::V5ARU.bits()
+                        | Rules::V5U_S.bits()
+                        | Rules::VS.bits()
+                        | Rules::VK.bits()
+                        | Rules::VZ.bits(),
+                ),
+            ),
+            r("すべからず", "する", Rules::empty(), Rules::VS),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::GOTOSHI,
+        rules: Cow::Borrowed(&[
+            r(
+                "がごとし",
+                "",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V1.bits()
+                        | Rules::V5.bits()
+                        | Rules::V5S.bits()
+                        | Rules::V5K_S.bits()
+                        | Rules::V5R_I.bits()
+                        | Rules::V5ARU.bits()
+                        | Rules::V5U_S.bits()
+                        | Rules::VS.bits()
+                        | Rules::VK.bits()
+                        | Rules::VZ.bits(),
+                ),
+            ),
+            r(
+                "がごとき",
+                "",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V1.bits()
+                        | Rules::V5.bits()
+                        | Rules::V5S.bits()
+                        | Rules::V5K_S.bits()
+                        | Rules::V5R_I.bits()
+                        | Rules::V5ARU.bits()
+                        | Rules::V5U_S.bits()
+                        | Rules::VS.bits()
+                        | Rules::VK.bits()
+                        | Rules::VZ.bits(),
+                ),
+            ),
+            r(
+                "がごとく",
+                "",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V1.bits()
+                        | Rules::V5.bits()
+                        | Rules::V5S.bits()
+                        | Rules::V5K_S.bits()
+                        | Rules::V5R_I.bits()
+                        | Rules::V5ARU.bits()
+                        | Rules::V5U_S.bits()
+                        | Rules::VS.bits()
+                        | Rules::VK.bits()
+                        | Rules::VZ.bits(),
+                ),
+            ),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::PERFECTIVE,
+        rules: Cow::Borrowed(&[
+            r(
+                "えり",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "けり",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("げり", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "せり",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("てり", "つ", Rules::empty(), Rules::V5),
+            r("ねり", "ぬ", Rules::empty(), Rules::V5),
+            r("べり", "ぶ", Rules::empty(), Rules::V5),
+            r("めり", "む", Rules::empty(), Rules::V5),
+            r("せり", "する", Rules::empty(), Rules::VS),
+            r(
+                "いたり",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きたり",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎたり", "ぐ", Rules::empty(), Rules::V5),
+            r("ちたり", "つ", Rules::empty(), Rules::V5),
+            r("にたり", "ぬ", Rules::empty(), Rules::V5),
+            r("びたり", "ぶ", Rules::empty(), Rules::V5),
+            r("みたり", "む", Rules::empty(), Rules::V5),
+            r(
+                "りたり",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KERI,
+        rules: Cow::Borrowed(&[
+            r("けり", "る", Rules::empty(), Rules::V1),
+            r(
+                "いけり",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きけり",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎけり", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "しけり",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちけり", "つ", Rules::empty(), Rules::V5),
+            r("にけり", "ぬ", Rules::empty(), Rules::V5),
+            r("びけり", "ぶ", Rules::empty(), Rules::V5),
+            r("みけり", "む", Rules::empty(), Rules::V5),
+            r(
+                "りけり",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("じけり", "ずる", Rules::empty(), Rules::VZ),
+            r("しけり", "する", Rules::empty(), Rules::VS),
+            r("為けり", "為る", Rules::empty(), Rules::VS),
+            r("きけり", "くる", Rules::empty(), Rules::VK),
+            r("来けり", "来る", Rules::empty(), Rules::VK),
+            r("來けり", "來る", Rules::empty(), Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::MU,
+        rules: Cow::Borrowed(&[
+            r("む", "る", Rules::empty(), Rules::V1),
+            r(
+                "かむ",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がむ", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "さむ",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たむ", "つ", Rules::empty(), Rules::V5),
+            r("なむ", "ぬ", Rules::empty(), Rules::V5),
+            r("ばむ", "ぶ", Rules::empty(), Rules::V5),
+            r("まむ", "む", Rules::empty(), Rules::V5),
+            r(
+                "らむ",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r(
+                "わむ",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("ぜむ", "ずる", Rules::empty(), Rules::VZ),
+            r("せむ", "する", Rules::empty(), Rules::VS),
+            r("為む", "為る", Rules::empty(), Rules::VS),
+            r("こむ", "くる", Rules::empty(), Rules::VK),
+            r("来む", "来る", Rules::empty(), Rules::VK),
+            r("來む", "來る", Rules::empty(), Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::NUREBA,
+        rules: Cow::Borrowed(&[
+            r("ぬれば", "る", Rules::empty(), Rules::V1),
+            r(
+                "いぬれば",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きぬれば",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎぬれば", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "しぬれば",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちぬれば", "つ", Rules::empty(), Rules::V5),
+            r("にぬれば", "ぬ", Rules::empty(), Rules::V5),
+            r("びぬれば", "ぶ", Rules::empty(), Rules::V5),
+            r("みぬれば", "む", Rules::empty(), Rules::V5),
+            r(
+                "りぬれば",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("じぬれば", "ずる", Rules::empty(), Rules::VZ),
+            r("しぬれば", "する", Rules::empty(), Rules::VS),
+            r("為ぬれば", "為る", Rules::empty(), Rules::VS),
+            r("きぬれば", "くる", Rules::empty(), Rules::VK),
+            r("来ぬれば", "来る", Rules::empty(), Rules::VK),
+            r("來ぬれば", "來る", Rules::empty(), Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::SHI,
+        rules: Cow::Borrowed(&[
+            r("し", "い", Rules::empty(), Rules::ADJ_I),
+            r("し", "しい", Rules::empty(), Rules::ADJ_I),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KARI,
+        rules: Cow::Borrowed(&[r("かる", "い", Rules::V5, Rules::ADJ_I)]),
+    },
+];
+
+/// The Kansai dialect, like 行かへん, 行かはる and 静かや.
+pub static KANSAI_RULES: &[InflectionRules] = &[
+    InflectionRules {
+        reason: Reasons::KANSAI_NEGATIVE,
+        rules: Cow::Borrowed(&[
+            r("へん", "る", Rules::empty(), Rules::V1),
+            r("いひん", "る", Rules::empty(), Rules::V1),
+            r(
+                "かへん",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r(
+                "けへん",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がへん", "ぐ", Rules::empty(), Rules::V5),
+            r("げへん", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "さへん",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r(
+                "せへん",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たへん", "つ", Rules::empty(), Rules::V5),
+            r("てへん", "つ", Rules::empty(), Rules::V5),
+            r("なへん", "ぬ", Rules::empty(), Rules::V5),
+            r("ねへん", "ぬ", Rules::empty(), Rules::V5),
+            r("ばへん", "ぶ", Rules::empty(), Rules::V5),
+            r("べへん", "ぶ", Rules::empty(), Rules::V5),
+            r("まへん", "む", Rules::empty(), Rules::V5),
+            r("めへん", "む", Rules::empty(), Rules::V5),
+            r(
+                "らへん",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5ARU.bits() | Rules::V5R_I.bits(),
+                ),
+            ),
+            r(
+                "れへん",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5ARU.bits() | Rules::V5R_I.bits(),
+                ),
+            ),
+            r(
+                "わへん",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "えへん",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("せえへん", "する", Rules::empty(), Rules::VS),
+            r("せーへん", "する", Rules::empty(), Rules::VS),
+            r("しいひん", "する", Rules::empty(), Rules::VS),
+            r("しへん", "する", Rules::empty(), Rules::VS),
+            r("けえへん", "くる", Rules::empty(), Rules::VK),
+            r("けーへん", "くる", Rules::empty(), Rules::VK),
+            r("こーへん", "くる", Rules::empty(), Rules::VK),
+            r("こおへん", "くる", Rules::empty(), Rules::VK),
+            r("来へん", "来る", Rules::empty(), Rules::VK),
+            r("来えへん", "来る", Rules::empty(), Rules::VK),
+            r("きいひん", "くる", Rules::empty(), Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KANSAI_NEGATIVE_PAST,
+        rules: Cow::Borrowed(&[
+            r("へんかった", "る", Rules::empty(), Rules::V1),
+            r("いひんかった", "る", Rules::empty(), Rules::V1),
+            r(
+                "かへんかった",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r(
+                "けへんかった",
+                "く",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がへんかった", "ぐ", Rules::empty(), Rules::V5),
+            r("げへんかった", "ぐ", Rules::empty(), Rules::V5),
+            r(
+                "さへんかった",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r(
+                "せへんかった",
+                "す",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たへんかった", "つ", Rules::empty(), Rules::V5),
+            r("てへんかった", "つ", Rules::empty(), Rules::V5),
+            r("なへんかった", "ぬ", Rules::empty(), Rules::V5),
+            r("ねへんかった", "ぬ", Rules::empty(), Rules::V5),
+            r("ばへんかった", "ぶ", Rules::empty(), Rules::V5),
+            r("べへんかった", "ぶ", Rules::empty(), Rules::V5),
+            r("まへんかった", "む", Rules::empty(), Rules::V5),
+            r("めへんかった", "む", Rules::empty(), Rules::V5),
+            r(
+                "らへんかった",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5ARU.bits() | Rules::V5R_I.bits(),
+                ),
+            ),
+            r(
+                "れへんかった",
+                "る",
+                Rules::empty(),
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5ARU.bits() | Rules::V5R_I.bits(),
+                ),
+            ),
+            r(
+                "わへんかった",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "えへんかった",
+                "う",
+                Rules::empty(),
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r("せえへんかった", "する", Rules::empty(), Rules::VS),
+            r("せーへんかった", "する", Rules::empty(), Rules::VS),
+            r("しいひんかった", "する", Rules::empty(), Rules::VS),
+            r("しへんかった", "する", Rules::empty(), Rules::VS),
+            r("けえへんかった", "くる", Rules::empty(), Rules::VK),
+            r("けーへんかった", "くる", Rules::empty(), Rules::VK),
+            r("こーへんかった", "くる", Rules::empty(), Rules::VK),
+            r("こおへんかった", "くる", Rules::empty(), Rules::VK),
+            r("来へんかった", "来る", Rules::empty(), Rules::VK),
+            r("来えへんかった", "来る", Rules::empty(), Rules::VK),
+            r("きいひんかった", "くる", Rules::empty(), Rules::VK),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KANSAI_HONORIFIC,
+        rules: Cow::Borrowed(&[
+            r("はる", "る", Rules::V5, Rules::V1),
+            r(
+                "いはる",
+                "う",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+            r(
+                "きはる",
+                "く",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("ぎはる", "ぐ", Rules::V5, Rules::V5),
+            r(
+                "しはる",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("ちはる", "つ", Rules::V5, Rules::V5),
+            r("にはる", "ぬ", Rules::V5, Rules::V5),
+            r("びはる", "ぶ", Rules::V5, Rules::V5),
+            r("みはる", "む", Rules::V5, Rules::V5),
+            r(
+                "りはる",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(
+                    Rules::V5.bits() | Rules::V5R_I.bits() | Rules::V5ARU.bits(),
+                ),
+            ),
+            r("じはる", "ずる", Rules::V5, Rules::VZ),
+            r("しはる", "する", Rules::V5, Rules::VS),
+            r("為はる", "為る", Rules::V5, Rules::VS),
+            r("きはる", "くる", Rules::V5, Rules::VK),
+            r("来はる", "来る", Rules::V5, Rules::VK),
+            r("來はる", "來る", Rules::V5, Rules::VK),
+            r(
+                "かはる",
+                "く",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5K_S.bits()),
+            ),
+            r("がはる", "ぐ", Rules::V5, Rules::V5),
+            r(
+                "さはる",
+                "す",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5S.bits()),
+            ),
+            r("たはる", "つ", Rules::V5, Rules::V5),
+            r("なはる", "ぬ", Rules::V5, Rules::V5),
+            r("ばはる", "ぶ", Rules::V5, Rules::V5),
+            r("まはる", "む", Rules::V5, Rules::V5),
+            r(
+                "らはる",
+                "る",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5ARU.bits()),
+            ),
+            r(
+                "わはる",
+                "う",
+                Rules::V5,
+                Rules::from_bits_retain(Rules::V5.bits() | Rules::V5U_S.bits()),
+            ),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KANSAI_PROGRESSIVE,
+        rules: Cow::Borrowed(&[
+            r("とる", "ている", Rules::V5, Rules::V1),
+            r("どる", "でいる", Rules::V5, Rules::V1),
+        ]),
+    },
+    InflectionRules {
+        reason: Reasons::KANSAI_COPULA,
+        rules: Cow::Borrowed(&[
+            r("や", "だ", Rules::empty(), Rules::COP),
+            r("やん", "だ", Rules::empty(), Rules::COP),
+            r("やった", "だった", Rules::empty(), Rules::empty()),
+        ]),
     },
 ];
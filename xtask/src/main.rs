@@ -0,0 +1,274 @@
+//! Development tasks, run with `cargo xtask <task>`.
+//!
+//! - `codegen`: regenerate `src/rules.rs` from the rule sets in `data/`.
+use serde::de::{MapAccess, Visitor};
+use serde::{Deserialize, Deserializer};
+use std::error::Error;
+use std::fmt::{self, Write as _};
+use std::io::Write as _;
+use std::path::PathBuf;
+use std::process::{Command, Stdio};
+
+fn main() -> Result<(), Box<dyn Error>> {
+    match std::env::args().nth(1).as_deref() {
+        Some("codegen") => {
+            std::fs::write(rules_rs_path(), generate_rules_rs()?)?;
+            Ok(())
+        }
+        _ => Err("usage: cargo xtask codegen".into()),
+    }
+}
+
+fn root_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("..")
+}
+
+fn rules_rs_path() -> PathBuf {
+    root_path().join("src").join("rules.rs")
+}
+
+/// A file in `data/` and the static its rules are generated into.
+struct RuleSet {
+    file: &'static str,
+    name: &'static str,
+    /// The name of the [`Reasons`] constant holding the reasons that first
+    /// appear in this rule set.
+    reasons: Option<&'static str>,
+    doc: &'static str,
+}
+
+const RULE_SETS: &[RuleSet] = &[
+    RuleSet {
+        file: "deinflect.json",
+        name: "INFLECTION_RULES",
+        reasons: None,
+        doc: "The rules of standard modern Japanese, used by [`Deinflector::default`](super::Deinflector::default).",
+    },
+    RuleSet {
+        file: "colloquial.json",
+        name: "COLLOQUIAL_RULES",
+        reasons: Some("COLLOQUIAL"),
+        doc: "Contractions of spoken Japanese like 行かなきゃ, 見ちゃ and 知らん.",
+    },
+    RuleSet {
+        file: "classical.json",
+        name: "CLASSICAL_RULES",
+        reasons: Some("CLASSICAL"),
+        doc: "Auxiliaries and adjective forms of classical Japanese like 行かざる, 行くべし and 高からず, deinflected to the modern dictionary form.",
+    },
+    RuleSet {
+        file: "kansai.json",
+        name: "KANSAI_RULES",
+        reasons: Some("KANSAI"),
+        doc: "The Kansai dialect, like 行かへん, 行かはる and 静かや.",
+    },
+];
+
+/// Generate the contents of `src/rules.rs`, that is the [`Reasons`] flags in
+/// the order of `data/reasons.json` and a static for each rule set.
+fn generate_rules_rs() -> Result<String, Box<dyn Error>> {
+    let mut sets = Vec::new();
+    for set in RULE_SETS {
+        let json = std::fs::read_to_string(root_path().join("data").join(set.file))?;
+        let RuleFile(reasons) = serde_json::from_str(&json)?;
+        sets.push((set, reasons));
+    }
+
+    // the reasons in the order of their bits, with the set they first appear in
+    let json = std::fs::read_to_string(root_path().join("data").join("reasons.json"))?;
+    let order: Vec<String> = serde_json::from_str(&json)?;
+    for (set, rules) in &sets {
+        if let Some((reason, _)) = rules.iter().find(|(r, _)| !order.contains(r)) {
+            let message = format!(
+                "{reason} in data/{} is missing from data/reasons.json, add it to the end",
+                set.file
+            );
+            return Err(message.into());
+        }
+    }
+    let mut reasons: Vec<(&str, &RuleSet)> = Vec::new();
+    for reason in &order {
+        let (set, _) = sets
+            .iter()
+            .find(|(_, rules)| rules.iter().any(|(r, _)| r == reason))
+            .ok_or_else(|| format!("{reason} isn't used by any rule set"))?;
+        reasons.push((reason, set));
+    }
+
+    if reasons.len() > 128 {
+        return Err(format!("{} reasons don't fit into a u128", reasons.len()).into());
+    }
+
+    let mut out = String::from(
+        "// Generated by `cargo xtask codegen` from the rule sets in data/, do not edit.\n\
+         \n\
+         use super::{r, rp, InflectionRules, Rules};\n\
+         use bitflags::bitflags;\n\
+         use std::borrow::Cow;\n\
+         \n\
+         bitflags! {\n    \
+             /// The reasons a word was inflected for, one flag per reason of the rules.\n    \
+             ///\n    \
+             /// New reasons are only ever appended, so the bits of existing ones are\n    \
+             /// stable. Human readable formats serialize the names of the flags, e.g.\n    \
+             /// `\"NEGATIVE | PAST\"`, which doesn't depend on the bits at all.\n    \
+             ///\n    \
+             /// Binary formats like bincode or postcard serialize the bits, since\n    \
+             /// version 0.2 as a `u128` instead of a `u64`, so data written by\n    \
+             /// earlier versions can't be read back.\n    \
+             #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]\n    \
+             #[cfg_attr(feature = \"serde\", derive(serde::Serialize, serde::Deserialize), serde(transparent))]\n    \
+             pub struct Reasons: u128 {\n",
+    );
+    for (i, (reason, _)) in reasons.iter().enumerate() {
+        let bit = if i == 0 {
+            "1".to_string()
+        } else {
+            format!("1 << {i}")
+        };
+        writeln!(out, "        const {} = {bit};", flag_name(reason))?;
+    }
+    out += "    }\n}\n\nimpl Reasons {\n";
+
+    for set in RULE_SETS {
+        let Some(name) = set.reasons else { continue };
+        let bits = reasons
+            .iter()
+            .filter(|(_, s)| s.name == set.name)
+            .map(|(reason, _)| format!("Self::{}.bits()", flag_name(reason)))
+            .collect::<Vec<_>>()
+            .join(" | ");
+        writeln!(out, "/// The reasons of [`{}`].", set.name)?;
+        writeln!(
+            out,
+            "pub const {name}: Self = Self::from_bits_retain({bits});"
+        )?;
+    }
+    out += "}\n";
+
+    for (set, rules) in &sets {
+        writeln!(out, "\n/// {}", set.doc)?;
+        writeln!(out, "pub static {}: &[InflectionRules] = &[", set.name)?;
+        for (reason, rules) in rules {
+            writeln!(
+                out,
+                "InflectionRules {{ reason: Reasons::{}, rules: Cow::Borrowed(&[",
+                flag_name(reason)
+            )?;
+            for rule in rules {
+                if !rule.prefix_in.is_empty() || !rule.prefix_out.is_empty() {
+                    write!(out, "rp({:?}, {:?}, ", rule.prefix_in, rule.prefix_out)?;
+                } else {
+                    out += "r(";
+                }
+                writeln!(
+                    out,
+                    "{:?}, {:?}, {}, {}),",
+                    rule.kana_in,
+                    rule.kana_out,
+                    rules_expr(&rule.rules_in),
+                    rules_expr(&rule.rules_out)
+                )?;
+            }
+            out += "]) },\n";
+        }
+        out += "];\n";
+    }
+
+    rustfmt(&out)
+}
+
+/// `"polite past"` -> `POLITE_PAST`, `"adj-i"` -> `ADJ_I`
+fn flag_name(name: &str) -> String {
+    name.trim_start_matches('-')
+        .replace([' ', '-'], "_")
+        .to_uppercase()
+}
+
+fn rules_expr(rules: &[String]) -> String {
+    match rules {
+        [] => "Rules::empty()".to_string(),
+        [rule] => format!("Rules::{}", flag_name(rule)),
+        rules => {
+            let bits = rules
+                .iter()
+                .map(|rule| format!("Rules::{}.bits()", flag_name(rule)))
+                .collect::<Vec<_>>()
+                .join(" | ");
+            format!("Rules::from_bits_retain({bits})")
+        }
+    }
+}
+
+fn rustfmt(source: &str) -> Result<String, Box<dyn Error>> {
+    let mut rustfmt = Command::new("rustfmt")
+        .args(["--edition", "2021"])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()?;
+    rustfmt
+        .stdin
+        .take()
+        .ok_or("rustfmt has no stdin")?
+        .write_all(source.as_bytes())?;
+    let output = rustfmt.wait_with_output()?;
+    if !output.status.success() {
+        return Err("rustfmt failed".into());
+    }
+    Ok(String::from_utf8(output.stdout)?)
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RuleJson {
+    #[serde(default)]
+    prefix_in: String,
+    #[serde(default)]
+    prefix_out: String,
+    kana_in: String,
+    kana_out: String,
+    rules_in: Vec<String>,
+    rules_out: Vec<String>,
+}
+
+/// The reasons of a `deinflect.json` in the order they appear in the file.
+struct RuleFile(Vec<(String, Vec<RuleJson>)>);
+
+impl<'de> Deserialize<'de> for RuleFile {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct RuleFileVisitor;
+
+        impl<'de> Visitor<'de> for RuleFileVisitor {
+            type Value = RuleFile;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a map from reasons to lists of rules")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+                let mut entries = Vec::new();
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(RuleFile(entries))
+            }
+        }
+
+        deserializer.deserialize_map(RuleFileVisitor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rules_rs_is_up_to_date() {
+        let generated = generate_rules_rs().unwrap();
+        let committed = std::fs::read_to_string(rules_rs_path()).unwrap();
+        assert!(
+            generated == committed,
+            "src/rules.rs is out of date, run `cargo xtask codegen`"
+        );
+    }
+}